@@ -1,26 +1,113 @@
 use anyhow::Result;
 use pico_args::Arguments;
 
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::collectors::{DiskFilter, DuOptions, InterfaceClass, NetworkFilter, ProbeOptions, ProcessSort, SocketFilter};
+
+/// Subcommands that replace the regular information display.
+#[derive(Debug, Clone)]
+pub enum Command {
+    Du(DuOptions),
+    Probe(ProbeOptions),
+}
+
 pub struct CliArgs {
+    pub command: Option<Command>,
     pub show_cpu: bool,
     pub show_memory: bool,
     pub show_system: bool,
     pub show_battery: bool,
     pub show_disk: bool,
+    pub show_diskio: bool,
+    pub show_blockdev: bool,
+    pub show_storage: bool,
+    pub show_fstab: bool,
     pub show_network: bool,
+    pub show_routes: bool,
+    pub show_neighbors: bool,
+    pub show_netns: bool,
+    pub show_sockets: bool,
+    pub show_ports: bool,
+    pub show_netstat: bool,
+    pub show_conntrack: bool,
     pub show_temp: bool,
+    pub show_sensors: bool,
     pub show_process: bool,
     pub show_hosts: bool,
+    pub show_dns: bool,
+    pub show_zram: bool,
     pub show_all: bool,
     pub json: bool,
+    pub check: bool,
     pub watch: bool,
     pub interval: u64,
     pub process_top: Option<usize>,
-    pub process_sort_cpu: bool,
+    /// Explicit sort order; `None` picks the default for the current view
+    pub process_sort: Option<ProcessSort>,
     pub hosts_filter_comments: bool,
+    /// Name to look up through the configured resolver order
+    pub resolve_name: Option<String>,
+    pub leak_window: usize,
+    pub disk_filter: DiskFilter,
+    pub socket_filter: SocketFilter,
+    pub network_filter: NetworkFilter,
+    pub disk_history: Option<PathBuf>,
+    pub fill_horizon_hours: u64,
+    /// Conntrack table usage, in percent, above which a warning is shown
+    pub conntrack_threshold: f64,
     pub help: bool,
 }
 
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            command: None,
+            show_cpu: false,
+            show_memory: false,
+            show_system: false,
+            show_battery: false,
+            show_disk: false,
+            show_diskio: false,
+            show_blockdev: false,
+            show_storage: false,
+            show_fstab: false,
+            show_network: false,
+            show_routes: false,
+            show_neighbors: false,
+            show_netns: false,
+            show_sockets: false,
+            show_ports: false,
+            show_netstat: false,
+            show_conntrack: false,
+            show_temp: false,
+            show_sensors: false,
+            show_process: false,
+            show_hosts: false,
+            show_dns: false,
+            show_zram: false,
+            show_all: false,
+            json: false,
+            check: false,
+            watch: false,
+            interval: 2,
+            process_top: None,
+            process_sort: None,
+            hosts_filter_comments: true,
+            resolve_name: None,
+            leak_window: 5,
+            disk_filter: DiskFilter::default(),
+            socket_filter: SocketFilter::default(),
+            network_filter: NetworkFilter::default(),
+            disk_history: None,
+            fill_horizon_hours: 24,
+            conntrack_threshold: 80.0,
+            help: false,
+        }
+    }
+}
+
 impl CliArgs {
     pub fn parse() -> Result<Self> {
         let mut args = Arguments::from_env();
@@ -29,43 +116,119 @@ impl CliArgs {
 
         if help {
             return Ok(Self {
-                show_cpu: false,
-                show_memory: false,
-                show_system: false,
-                show_battery: false,
-                show_disk: false,
-                show_network: false,
-                show_temp: false,
-                show_process: false,
-                show_hosts: false,
-                show_all: false,
-                json: false,
-                watch: false,
-                interval: 2,
-                process_top: None,
-                process_sort_cpu: false,
-                hosts_filter_comments: true,
                 help: true,
+                ..Self::default()
             });
         }
 
+        let subcommand = args.subcommand()?;
+
         let show_cpu = args.contains("--cpu");
         let show_memory = args.contains("--memory");
         let show_system = args.contains("--system");
         let show_battery = args.contains("--battery");
         let show_disk = args.contains("--disk");
+        let show_diskio = args.contains("--diskio");
+        let show_blockdev = args.contains("--blockdev");
+        let show_storage = args.contains("--storage");
+        let show_fstab = args.contains("--fstab");
         let show_network = args.contains("--network");
+        let show_routes = args.contains("--routes");
+        let show_neighbors = args.contains("--neighbors");
+        let show_netns = args.contains("--netns");
+        let show_sockets = args.contains("--sockets");
+        let show_ports = args.contains("--ports");
+        let show_netstat = args.contains("--netstat");
+        let show_conntrack = args.contains("--conntrack");
         let show_temp = args.contains("--temp");
+        let show_sensors = args.contains("--sensors");
         let show_process = args.contains("--process");
         let show_hosts = args.contains("--hosts");
+        let resolve_name: Option<String> = args.opt_value_from_str("--resolve")?;
+        let show_dns = args.contains("--dns") || resolve_name.is_some();
+        let show_zram = args.contains("--zram");
         let json = args.contains("--json");
+        let check = args.contains("--check");
         let watch = args.contains(["-w", "--watch"]);
         let interval: u64 = args.opt_value_from_str(["-i", "--interval"])?.unwrap_or(2);
         let process_top: Option<usize> = args.opt_value_from_str("--top")?;
-        let process_sort_cpu = args.contains("--sort-cpu");
+        let process_sort = if args.contains("--sort-io") {
+            Some(ProcessSort::Io)
+        } else if args.contains("--sort-cpu") {
+            Some(ProcessSort::Cpu)
+        } else if args.contains("--sort-mem") {
+            Some(ProcessSort::Memory)
+        } else {
+            None
+        };
         let hosts_filter_comments = !args.contains("--show-comments");
+        let leak_window: usize = args.opt_value_from_str("--leak-window")?.unwrap_or(5);
+        let disk_filter = DiskFilter {
+            include_fs_types: parse_list(&mut args, "--fs-type")?,
+            exclude_fs_types: parse_list(&mut args, "--exclude-fs-type")?,
+            include_mounts: parse_list(&mut args, "--mount")?,
+            exclude_mounts: parse_list(&mut args, "--exclude-mount")?,
+            hide_pseudo: args.contains("--hide-pseudo"),
+        };
+        let socket_filter = SocketFilter {
+            states: parse_list(&mut args, "--state")?,
+            ports: parse_list(&mut args, "--port")?
+                .iter()
+                .map(|p| p.parse::<u16>().map_err(|_| anyhow::anyhow!("Invalid port: {}", p)))
+                .collect::<Result<_>>()?,
+        };
+        let network_filter = NetworkFilter {
+            include_names: parse_list(&mut args, "--iface")?,
+            exclude_names: parse_list(&mut args, "--exclude-iface")?,
+            include_classes: parse_classes(&mut args, "--iface-class")?,
+            exclude_classes: parse_classes(&mut args, "--exclude-iface-class")?,
+            show_all: args.contains("--all-interfaces"),
+        };
+        let disk_history: Option<PathBuf> = args.opt_value_from_str("--disk-history")?;
+        let fill_horizon_hours: u64 = args.opt_value_from_str("--fill-horizon")?.unwrap_or(24);
+        if fill_horizon_hours.checked_mul(3600).is_none() {
+            anyhow::bail!("Invalid fill horizon: {} hours", fill_horizon_hours);
+        }
+        let conntrack_threshold: f64 = args.opt_value_from_str("--conntrack-threshold")?.unwrap_or(80.0);
+        if !(0.0..=100.0).contains(&conntrack_threshold) {
+            anyhow::bail!("Invalid conntrack threshold: {} (expected 0-100)", conntrack_threshold);
+        }
 
-        let show_all = !show_cpu && !show_memory && !show_system && !show_battery && !show_disk && !show_network && !show_temp && !show_process && !show_hosts;
+        let show_all = !show_cpu && !show_memory && !show_system && !show_battery && !show_disk && !show_diskio && !show_blockdev && !show_storage && !show_fstab && !show_network && !show_routes && !show_neighbors && !show_netns && !show_sockets && !show_ports && !show_netstat && !show_conntrack && !show_temp && !show_sensors && !show_process && !show_hosts && !show_dns && !show_zram;
+
+        let command = match subcommand.as_deref() {
+            None => None,
+            Some("du") => {
+                let max_depth: usize = args.opt_value_from_str(["-d", "--depth"])?.unwrap_or(1);
+                let one_file_system = args.contains(["-x", "--one-file-system"]);
+                let tree = args.contains("--tree");
+                let path: PathBuf = args.opt_free_from_str()?.unwrap_or_else(|| PathBuf::from("."));
+                Some(Command::Du(DuOptions {
+                    path,
+                    max_depth,
+                    top: process_top.or(Some(10)),
+                    one_file_system,
+                    tree,
+                }))
+            }
+            Some("probe") => {
+                let targets_file: Option<PathBuf> = args.opt_value_from_str("--targets")?;
+                let timeout: f64 = args.opt_value_from_str("--timeout")?.unwrap_or(3.0);
+                if !(timeout > 0.0 && timeout.is_finite()) {
+                    anyhow::bail!("Invalid timeout: {}", timeout);
+                }
+                let mut targets = Vec::new();
+                while let Some(target) = args.opt_free_from_str::<String>()? {
+                    targets.push(target);
+                }
+                Some(Command::Probe(ProbeOptions {
+                    targets,
+                    targets_file,
+                    timeout: Duration::from_secs_f64(timeout),
+                }))
+            }
+            Some(other) => anyhow::bail!("Unknown command: {}", other),
+        };
 
         let remaining = args.finish();
         if !remaining.is_empty() {
@@ -73,22 +236,46 @@ impl CliArgs {
         }
 
         Ok(Self {
+            command,
             show_cpu,
             show_memory,
             show_system,
             show_battery,
             show_disk,
+            show_diskio,
+            show_blockdev,
+            show_storage,
+            show_fstab,
             show_network,
+            show_routes,
+            show_neighbors,
+            show_netns,
+            show_sockets,
+            show_ports,
+            show_netstat,
+            show_conntrack,
             show_temp,
+            show_sensors,
             show_process,
             show_hosts,
+            show_dns,
+            show_zram,
             show_all,
             json,
+            check,
             watch,
             interval,
             process_top,
-            process_sort_cpu,
+            process_sort,
             hosts_filter_comments,
+            resolve_name,
+            leak_window,
+            disk_filter,
+            socket_filter,
+            network_filter,
+            disk_history,
+            fill_horizon_hours,
+            conntrack_threshold,
             help,
         })
     }
@@ -99,6 +286,8 @@ impl CliArgs {
 
 USAGE:
     weni [OPTIONS]
+    weni du [PATH] [DU OPTIONS]
+    weni probe [HOST:PORT...] [PROBE OPTIONS]
 
 OPTIONS:
     --cpu                 Show CPU information
@@ -106,20 +295,83 @@ OPTIONS:
     --system              Show system information
     --battery             Show battery information
     --disk                Show disk information
-    --network             Show network information
+    --diskio              Show per-device I/O throughput, IOPS and latency (Linux)
+    --blockdev            Show the block device tree, like lsblk (Linux)
+    --storage             Show software RAID (md) and LVM volume health (Linux)
+    --fstab               Compare /etc/fstab and systemd mount units with the
+                          mounts that are actually active (Linux)
+    --network             Show per-interface throughput (bytes/s, packets/s) and counters
+    --routes              Show IPv4/IPv6 routes and policy rules (Linux)
+    --neighbors           Show the ARP and NDP neighbor tables with their states (Linux)
+    --netns               Show interfaces, addresses and counters in every network
+                          namespace, with the containers using them (Linux; root
+                          sees all namespaces)
+    --sockets             Show TCP/UDP sockets with owning processes, like ss (Linux)
+    --ports               Show listening ports with their process and user (Linux)
+    --netstat             Show TCP/UDP error counters (retransmits, resets, listen
+                          overflows, buffer errors) and socket memory (Linux)
+    --conntrack           Show connection tracking table usage and top talkers (Linux)
+    --conntrack-threshold <PCT>
+                          Warn (and fail --check) when the conntrack table is
+                          at least PCT percent full (default: 80)
     --temp                Show temperature information
+    --sensors             Show every hwmon sensor (temperature, fan, voltage, power,
+                          current) by chip, with limits and alarms, like lm-sensors (Linux)
     --process             Show running processes
     --hosts               Show hosts file contents
+    --dns                 Show resolver configuration (resolv.conf, nsswitch, systemd-resolved)
+    --resolve <NAME>      Resolve NAME in nsswitch order and report which source answered
+    --zram                Show zram devices and zswap pool statistics (Linux)
 
 GENERAL OPTIONS:
     --json                Output in JSON format
-    -w, --watch           Enable watch mode (live updates)
+    --check               Exit with status 2 when a health check fails
+    -w, --watch           Enable watch mode (live updates); with --json, prints
+                          one JSON document per tick
     -i, --interval <SEC>  Update interval in seconds (default: 2)
     -h, --help            Print help information
 
 PROCESS OPTIONS:
     --top <N>             Show only top N processes (sorted by resource usage)
     --sort-cpu            Sort processes by CPU usage (default: by memory)
+    --sort-mem            Sort processes by memory usage
+    --sort-io             Sort processes by disk read + write rate
+    --leak-window <N>     Watch ticks of memory history used to flag steady
+                          growth (default: 5)
+
+DISK OPTIONS:
+    --fs-type <LIST>          Only show these filesystem types (comma-separated globs)
+    --exclude-fs-type <LIST>  Hide these filesystem types
+    --mount <LIST>            Only show these mount points (comma-separated globs)
+    --exclude-mount <LIST>    Hide these mount points
+    --hide-pseudo             Hide tmpfs, overlay, squashfs and other virtual filesystems
+    --disk-history <FILE>     Record usage to FILE so fill rates span separate runs
+    --fill-horizon <HOURS>    Highlight mounts projected to fill within HOURS (default: 24)
+
+NETWORK OPTIONS:
+    --iface <LIST>                Only show these interfaces (comma-separated globs)
+    --exclude-iface <LIST>        Hide these interfaces
+    --iface-class <LIST>          Only show these classes: physical, loopback, bridge,
+                                  veth, tun, bond, vlan, wireless, virtual
+    --exclude-iface-class <LIST>  Hide these interface classes
+    --all-interfaces              Also show loopback and veth interfaces, hidden by default
+
+SOCKET OPTIONS:
+    --state <LIST>            Only show sockets in these states, e.g. close-wait,listen
+    --port <LIST>             Only show sockets with these local or remote ports;
+                              with --ports, only these listening ports
+
+DU OPTIONS:
+    Scans top-level directories of PATH in parallel, each one on a single thread
+    -d, --depth <N>           Levels of subdirectories to list (default: 1)
+    --top <N>                 Largest entries to keep per directory (default: 10)
+    -x, --one-file-system     Do not cross into other filesystems
+    --tree                    Show the result as a tree instead of a table
+
+PROBE OPTIONS:
+    Connects to every HOST:PORT over TCP; exits with status 2 when any is unreachable
+    --targets <FILE>          Read more HOST:PORT targets from FILE (# starts a comment)
+    --timeout <SEC>           Connect timeout per address (default: 3)
 
 HOSTS OPTIONS:
     --show-comments       Show comments in hosts file (default: filter out)
@@ -128,14 +380,56 @@ EXAMPLES:
     weni                        # Show all information
     weni --cpu --memory         # Show only CPU and memory
     weni --temp                 # Show temperature information
+    weni --sensors              # Fans, voltage rails and power draw per chip
     weni --process              # Show all running processes
     weni --process --top 10     # Show top 10 processes
     weni --process --sort-cpu   # Show processes sorted by CPU usage
+    weni --process --sort-io    # Find the process hammering the disk
     weni --hosts                # Show hosts file contents
+    weni --resolve db.internal  # Is the answer coming from /etc/hosts or DNS?
+    weni --disk --hide-pseudo   # Show only real storage
+    weni du /var -x --depth 2   # Largest directories under /var
+    weni probe db:5432 cache:6379 --timeout 1   # Can this host reach its dependencies?
+    weni --disk --watch         # Estimate fill rate and time to full per mount
+    weni --disk --watch --json  # Stream mount/unmount events as JSON lines
+    weni --disk --disk-history ~/.cache/weni-disks.json   # e.g. from cron
+    weni --storage --check      # Fail when a RAID array is degraded
+    weni --fstab --check        # Fail when a configured volume did not mount
+    weni --disk --exclude-mount '/snap/*'
+    weni --zram                 # Show zram/zswap compression statistics
+    weni --sockets --state close-wait   # Find connections stuck in CLOSE_WAIT
+    weni --ports --port 8080    # What is listening on 8080?
+    weni --netstat --watch      # Are listen queues overflowing right now?
+    weni --conntrack --check    # Fail when the NAT table is close to full
+    weni --network --iface-class physical,bond
+    weni --network --neighbors  # Debug L2: which neighbors are FAILED or STALE?
+    sudo weni --netns           # Interfaces inside every container
     weni --json                 # Output all info as JSON
     weni --watch                # Live monitoring mode
     weni --watch --interval 5   # Monitor with 5 second interval
+    weni --watch --process      # Flag processes with steadily growing memory
+    weni --watch --diskio       # Spot saturated disks, like iostat -x
 "#
         );
     }
 }
+
+/// Reads a comma-separated option value into a list; absent options yield an empty list.
+fn parse_list(args: &mut Arguments, key: &'static str) -> Result<Vec<String>> {
+    let value: Option<String> = args.opt_value_from_str(key)?;
+    Ok(value
+        .map(|v| {
+            v.split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+fn parse_classes(args: &mut Arguments, key: &'static str) -> Result<Vec<InterfaceClass>> {
+    parse_list(args, key)?
+        .iter()
+        .map(|name| InterfaceClass::from_name(name).ok_or_else(|| anyhow::anyhow!("Unknown interface class: {}", name)))
+        .collect()
+}
@@ -0,0 +1,171 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::disk::read_mountinfo;
+use super::sysfs::{active_choice, read_string, read_u64};
+
+const SYS_BLOCK: &str = "/sys/block";
+const SECTOR_SIZE: u64 = 512;
+/// Guards against holder cycles in a malformed sysfs tree
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockDevice {
+    pub name: String,
+    /// Kernel device name, e.g. `dm-0` for the mapper volume shown as `name`
+    pub kernel_name: String,
+    pub device_type: String,
+    pub size: u64,
+    pub model: Option<String>,
+    pub rotational: Option<bool>,
+    pub scheduler: Option<String>,
+    pub removable: bool,
+    pub read_only: bool,
+    pub mount_points: Vec<String>,
+    pub backing_file: Option<String>,
+    pub children: Vec<BlockDevice>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockDevicesInfo {
+    pub devices: Vec<BlockDevice>,
+    pub error: Option<String>,
+}
+
+impl BlockDevicesInfo {
+    pub fn collect() -> Self {
+        let entries = match fs::read_dir(SYS_BLOCK) {
+            Ok(entries) => entries,
+            Err(e) => {
+                return Self {
+                    devices: vec![],
+                    error: Some(format!("Failed to read {}: {} (Linux only)", SYS_BLOCK, e)),
+                }
+            }
+        };
+
+        let mounts = Self::mount_points_by_device();
+        let mut devices: Vec<BlockDevice> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            // Stacked devices (LVM, dm-crypt, md) are shown under the devices they sit on
+            .filter(|path| list_dir(&path.join("slaves")).is_empty())
+            .filter_map(|path| Self::read_device(&path, &mounts, 0))
+            .collect();
+
+        devices.sort_by(|a, b| a.kernel_name.cmp(&b.kernel_name));
+
+        Self {
+            devices,
+            error: None,
+        }
+    }
+
+    /// Builds a `major:minor` to mount point map, with swap areas shown as `[SWAP]`.
+    fn mount_points_by_device() -> HashMap<String, Vec<String>> {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        for mount in read_mountinfo() {
+            map.entry(mount.device_id()).or_default().push(mount.mount_point);
+        }
+
+        if let Ok(swaps) = fs::read_to_string("/proc/swaps") {
+            for line in swaps.lines().skip(1) {
+                let Some(path) = line.split_whitespace().next() else {
+                    continue;
+                };
+                let name = Path::new(path).file_name().map(|n| n.to_string_lossy().to_string());
+                if let Some(dev) = name.and_then(|n| read_string(&Path::new(SYS_BLOCK).join(n).join("dev"))) {
+                    map.entry(dev).or_default().push("[SWAP]".to_string());
+                }
+            }
+        }
+
+        map
+    }
+
+    fn read_device(path: &Path, mounts: &HashMap<String, Vec<String>>, depth: usize) -> Option<BlockDevice> {
+        let kernel_name = path.file_name()?.to_string_lossy().to_string();
+        let size = read_u64(&path.join("size"))? * SECTOR_SIZE;
+
+        // Unattached loop devices and ram disks have no size and only clutter the tree
+        if size == 0 && (kernel_name.starts_with("loop") || kernel_name.starts_with("ram")) {
+            return None;
+        }
+
+        let is_partition = path.join("partition").exists();
+        let dm_name = read_string(&path.join("dm/name"));
+        let device_type = if is_partition {
+            "part".to_string()
+        } else if let Some(uuid) = read_string(&path.join("dm/uuid")) {
+            if uuid.starts_with("LVM-") {
+                "lvm".to_string()
+            } else if uuid.starts_with("CRYPT-") {
+                "crypt".to_string()
+            } else {
+                "dm".to_string()
+            }
+        } else if kernel_name.starts_with("md") {
+            read_string(&path.join("md/level")).unwrap_or_else(|| "md".to_string())
+        } else if kernel_name.starts_with("loop") {
+            "loop".to_string()
+        } else if kernel_name.starts_with("sr") {
+            "rom".to_string()
+        } else {
+            "disk".to_string()
+        };
+
+        // Partitions inherit the queue settings of their parent disk
+        let queue = if is_partition {
+            path.parent().map(|p| p.join("queue"))
+        } else {
+            Some(path.join("queue"))
+        };
+
+        let mut children = Vec::new();
+        if depth < MAX_DEPTH {
+            if !is_partition {
+                for part in list_dir(path) {
+                    let part_path = path.join(&part);
+                    if part_path.join("partition").exists() {
+                        children.extend(Self::read_device(&part_path, mounts, depth + 1));
+                    }
+                }
+            }
+            for holder in list_dir(&path.join("holders")) {
+                children.extend(Self::read_device(&Path::new(SYS_BLOCK).join(holder), mounts, depth + 1));
+            }
+        }
+        children.sort_by(|a, b| a.kernel_name.cmp(&b.kernel_name));
+
+        Some(BlockDevice {
+            name: dm_name.unwrap_or_else(|| kernel_name.clone()),
+            kernel_name,
+            device_type,
+            size,
+            model: read_string(&path.join("device/model")),
+            rotational: queue
+                .as_ref()
+                .and_then(|q| read_u64(&q.join("rotational")))
+                .map(|r| r == 1),
+            scheduler: queue
+                .as_ref()
+                .and_then(|q| read_string(&q.join("scheduler")))
+                .and_then(|s| active_choice(&s)),
+            removable: read_u64(&path.join("removable")) == Some(1),
+            read_only: read_u64(&path.join("ro")) == Some(1),
+            mount_points: read_string(&path.join("dev"))
+                .and_then(|dev| mounts.get(&dev).cloned())
+                .unwrap_or_default(),
+            backing_file: read_string(&path.join("loop/backing_file")),
+            children,
+        })
+    }
+}
+
+fn list_dir(path: &Path) -> Vec<PathBuf> {
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| PathBuf::from(e.file_name())).collect())
+        .unwrap_or_default()
+}
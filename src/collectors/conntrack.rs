@@ -0,0 +1,193 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use super::sysfs::read_u64;
+
+const CONNTRACK_TABLE: &str = "/proc/net/nf_conntrack";
+/// Entries kept per top-talkers list
+const TOP_TALKERS: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConntrackCount {
+    /// Address, `address:port` or protocol name, depending on the list
+    pub key: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConntrackInfo {
+    pub count: u64,
+    pub max: u64,
+    pub usage_percent: f64,
+    pub threshold_percent: f64,
+    /// False when the per-connection table could not be read; `entries_error` says why
+    pub entries_readable: bool,
+    pub entries_error: Option<String>,
+    pub protocols: Vec<ConntrackCount>,
+    /// Original-direction source addresses with the most tracked connections
+    pub top_sources: Vec<ConntrackCount>,
+    /// Original-direction destinations, as `address:port` when the protocol has ports
+    pub top_destinations: Vec<ConntrackCount>,
+    pub error: Option<String>,
+}
+
+impl ConntrackInfo {
+    pub fn collect(threshold_percent: f64) -> Self {
+        let mut info = Self {
+            count: 0,
+            max: 0,
+            usage_percent: 0.0,
+            threshold_percent,
+            entries_readable: false,
+            entries_error: None,
+            protocols: vec![],
+            top_sources: vec![],
+            top_destinations: vec![],
+            error: None,
+        };
+
+        let count = read_u64(Path::new("/proc/sys/net/netfilter/nf_conntrack_count"));
+        let max = read_u64(Path::new("/proc/sys/net/netfilter/nf_conntrack_max"));
+        let (Some(count), Some(max)) = (count, max) else {
+            info.error = Some("Connection tracking is not active (nf_conntrack module not loaded)".to_string());
+            return info;
+        };
+        info.count = count;
+        info.max = max;
+        if max > 0 {
+            info.usage_percent = count as f64 / max as f64 * 100.0;
+        }
+
+        let file = match File::open(CONNTRACK_TABLE) {
+            Ok(file) => file,
+            Err(e) => {
+                info.entries_error = Some(match e.kind() {
+                    io::ErrorKind::PermissionDenied => {
+                        format!("Top talkers need read access to {} (run as root)", CONNTRACK_TABLE)
+                    }
+                    // Kernels built without CONFIG_NF_CONNTRACK_PROCFS only offer netlink
+                    io::ErrorKind::NotFound => {
+                        format!("Top talkers are unavailable: the kernel does not provide {}", CONNTRACK_TABLE)
+                    }
+                    _ => format!("Failed to read {}: {}", CONNTRACK_TABLE, e),
+                });
+                return info;
+            }
+        };
+        info.entries_readable = true;
+
+        let mut protocols: HashMap<String, u64> = HashMap::new();
+        let mut sources: HashMap<String, u64> = HashMap::new();
+        let mut destinations: HashMap<String, u64> = HashMap::new();
+
+        for line in BufReader::new(file).lines().map_while(Result::ok) {
+            let Some(entry) = parse_entry(&line) else {
+                continue;
+            };
+            *protocols.entry(entry.protocol).or_insert(0) += 1;
+            *sources.entry(entry.source).or_insert(0) += 1;
+            *destinations.entry(entry.destination).or_insert(0) += 1;
+        }
+
+        info.protocols = ranked(protocols, usize::MAX);
+        info.top_sources = ranked(sources, TOP_TALKERS);
+        info.top_destinations = ranked(destinations, TOP_TALKERS);
+
+        info
+    }
+
+    pub fn is_over_threshold(&self) -> bool {
+        self.max > 0 && self.usage_percent >= self.threshold_percent
+    }
+}
+
+struct ConntrackEntry {
+    protocol: String,
+    source: String,
+    destination: String,
+}
+
+/// Parses "ipv4 2 tcp 6 431999 ESTABLISHED src=10.0.0.1 dst=10.0.0.2 sport=51234 dport=443
+/// src=10.0.0.2 dst=10.0.0.1 ...". Only the first (original direction) tuple is used.
+fn parse_entry(line: &str) -> Option<ConntrackEntry> {
+    let mut fields = line.split_whitespace();
+    let protocol = fields.nth(2)?.to_string();
+
+    let (mut source, mut destination, mut port) = (None, None, None);
+    for field in fields {
+        let Some((key, value)) = field.split_once('=') else {
+            continue;
+        };
+        match key {
+            // The reply tuple repeats the keys; stop at its first one
+            "src" if source.is_some() => break,
+            "src" => source = Some(value),
+            "dst" => destination = Some(value),
+            "dport" => port = Some(value),
+            _ => {}
+        }
+    }
+
+    let destination = destination?;
+    Some(ConntrackEntry {
+        protocol,
+        source: source?.to_string(),
+        destination: match port {
+            Some(port) if destination.contains(':') => format!("[{}]:{}", destination, port),
+            Some(port) => format!("{}:{}", destination, port),
+            None => destination.to_string(),
+        },
+    })
+}
+
+/// Sorts by count, largest first, and keeps the first `limit` keys.
+fn ranked(counts: HashMap<String, u64>, limit: usize) -> Vec<ConntrackCount> {
+    let mut ranked: Vec<ConntrackCount> = counts
+        .into_iter()
+        .map(|(key, count)| ConntrackCount { key, count })
+        .collect();
+    ranked.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    ranked.truncate(limit);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_original_direction_tuple() {
+        let entry = parse_entry(
+            "ipv4     2 tcp      6 431999 ESTABLISHED src=10.0.0.1 dst=10.0.0.2 sport=51234 dport=443 src=10.0.0.2 dst=10.0.0.1 sport=443 dport=51234 [ASSURED] mark=0 zone=0 use=2",
+        )
+        .unwrap();
+        assert_eq!(entry.protocol, "tcp");
+        assert_eq!(entry.source, "10.0.0.1");
+        assert_eq!(entry.destination, "10.0.0.2:443");
+
+        let entry = parse_entry(
+            "ipv6     10 udp      17 29 src=fd00::2 dst=fd00::1 sport=40000 dport=53 [UNREPLIED] src=fd00::1 dst=fd00::2 sport=53 dport=40000 mark=0 zone=0 use=2",
+        )
+        .unwrap();
+        assert_eq!(entry.protocol, "udp");
+        assert_eq!(entry.destination, "[fd00::1]:53");
+
+        let entry = parse_entry(
+            "ipv4     2 icmp     1 29 src=10.0.0.1 dst=10.0.0.9 type=8 code=0 id=7 src=10.0.0.9 dst=10.0.0.1 type=0 code=0 id=7 mark=0 use=1",
+        )
+        .unwrap();
+        assert_eq!(entry.destination, "10.0.0.9");
+
+        assert!(parse_entry("ipv4 2").is_none());
+    }
+
+    #[test]
+    fn ranks_by_count_then_key() {
+        let counts = HashMap::from([("b".to_string(), 2), ("a".to_string(), 2), ("c".to_string(), 5)]);
+        let ranked: Vec<(String, u64)> = ranked(counts, 2).into_iter().map(|c| (c.key, c.count)).collect();
+        assert_eq!(ranked, vec![("c".to_string(), 5), ("a".to_string(), 2)]);
+    }
+}
@@ -1,6 +1,30 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 use sysinfo::Disks;
 
+use super::pattern::matches_any;
+
+/// Filesystem types that are virtual or read-only images rather than real storage
+const PSEUDO_FILESYSTEMS: &[&str] = &[
+    "tmpfs", "devtmpfs", "overlay", "squashfs", "proc", "sysfs", "cgroup", "cgroup2",
+    "devpts", "mqueue", "debugfs", "tracefs", "securityfs", "pstore", "bpf", "configfs",
+    "fusectl", "hugetlbfs", "autofs", "binfmt_misc", "nsfs", "ramfs", "efivarfs",
+];
+
+/// Samples older than this are dropped from the fill-rate history (7 days)
+const MAX_HISTORY_AGE_SECS: f64 = 7.0 * 24.0 * 3600.0;
+/// Upper bound on samples kept per mount point
+const MAX_HISTORY_SAMPLES: usize = 1000;
+
+/// Mount events kept for the watch-mode event log
+const EVENT_LOG_SIZE: usize = 20;
+
+/// Filesystems that are read-only by design, so `ro` on them is not a warning sign
+const READ_ONLY_FILESYSTEMS: &[&str] = &["squashfs", "iso9660", "udf", "erofs", "cramfs"];
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DiskInfo {
     pub name: String,
@@ -11,17 +35,195 @@ pub struct DiskInfo {
     pub usage_percent: f32,
     pub file_system: String,
     pub is_removable: bool,
+    pub total_inodes: Option<u64>,
+    pub used_inodes: Option<u64>,
+    pub free_inodes: Option<u64>,
+    pub inode_usage_percent: Option<f32>,
+    pub source: Option<String>,
+    /// Device number as `major:minor`
+    pub device_id: Option<String>,
+    pub mount_options: Vec<String>,
+    pub read_only: bool,
+    /// Growth of `used_space` in bytes per second, from the recorded history
+    pub fill_rate: Option<f64>,
+    pub time_to_full_secs: Option<u64>,
+    /// True when the projected time to full is within the configured horizon
+    pub full_soon: bool,
+}
+
+impl DiskInfo {
+    /// True when a normally writable filesystem is mounted read-only, which usually
+    /// means the kernel remounted it after I/O or filesystem errors.
+    pub fn unexpectedly_read_only(&self) -> bool {
+        self.read_only && !READ_ONLY_FILESYSTEMS.contains(&self.file_system.as_str())
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct DisksInfo {
     pub disks: Vec<DiskInfo>,
+    /// Mount changes detected since the previous watch tick
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<MountEvent>,
+    /// Recent mount changes across ticks, for the text event log
+    #[serde(skip)]
+    pub event_log: Vec<MountEvent>,
+    /// History keys of every mounted filesystem, including ones the disk filter hides
+    #[serde(skip)]
+    pub mounted: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MountEventKind {
+    Mounted,
+    Unmounted,
+    Remounted,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MountEvent {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub kind: MountEventKind,
+    pub mount_point: String,
+    pub device: String,
+    pub file_system: String,
+    pub removable: bool,
+    pub options: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_options: Option<Vec<String>>,
+}
+
+/// Compares the mount list between watch ticks to report mounts, unmounts and remounts.
+#[derive(Debug, Default)]
+pub struct MountWatcher {
+    previous: Option<HashMap<String, DiskInfo>>,
+    log: VecDeque<MountEvent>,
+}
+
+impl MountWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fills `info.events` with changes since the last call. The first call only
+    /// records the baseline.
+    pub fn record(&mut self, info: &mut DisksInfo) {
+        let current: HashMap<String, DiskInfo> = info
+            .disks
+            .iter()
+            .map(|d| (d.mount_point.clone(), d.clone()))
+            .collect();
+
+        if let Some(previous) = &self.previous {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let event = |kind, disk: &DiskInfo, previous_options| MountEvent {
+                timestamp,
+                kind,
+                mount_point: disk.mount_point.clone(),
+                device: disk.name.clone(),
+                file_system: disk.file_system.clone(),
+                removable: disk.is_removable,
+                options: disk.mount_options.clone(),
+                previous_options,
+            };
+
+            for disk in &info.disks {
+                match previous.get(&disk.mount_point) {
+                    None => info.events.push(event(MountEventKind::Mounted, disk, None)),
+                    Some(old) if old.name != disk.name => {
+                        info.events.push(event(MountEventKind::Unmounted, old, None));
+                        info.events.push(event(MountEventKind::Mounted, disk, None));
+                    }
+                    Some(old) if old.mount_options != disk.mount_options || old.read_only != disk.read_only => {
+                        info.events.push(event(
+                            MountEventKind::Remounted,
+                            disk,
+                            Some(old.mount_options.clone()),
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            let mut gone: Vec<&DiskInfo> = previous
+                .values()
+                .filter(|old| !current.contains_key(&old.mount_point))
+                .collect();
+            gone.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+            for old in gone {
+                info.events.push(event(MountEventKind::Unmounted, old, None));
+            }
+        }
+
+        self.log.extend(info.events.iter().cloned());
+        while self.log.len() > EVENT_LOG_SIZE {
+            self.log.pop_front();
+        }
+        info.event_log = self.log.iter().cloned().collect();
+        self.previous = Some(current);
+    }
+}
+
+/// Include/exclude rules applied to the mount list. Empty include lists match everything.
+#[derive(Debug, Clone, Default)]
+pub struct DiskFilter {
+    pub include_fs_types: Vec<String>,
+    pub exclude_fs_types: Vec<String>,
+    pub include_mounts: Vec<String>,
+    pub exclude_mounts: Vec<String>,
+    pub hide_pseudo: bool,
+}
+
+impl DiskFilter {
+    pub fn matches(&self, disk: &DiskInfo) -> bool {
+        if self.hide_pseudo && PSEUDO_FILESYSTEMS.contains(&disk.file_system.as_str()) {
+            return false;
+        }
+        if !self.include_fs_types.is_empty() && !matches_any(&self.include_fs_types, &disk.file_system) {
+            return false;
+        }
+        if matches_any(&self.exclude_fs_types, &disk.file_system) {
+            return false;
+        }
+        if !self.include_mounts.is_empty() && !matches_any(&self.include_mounts, &disk.mount_point) {
+            return false;
+        }
+        !matches_any(&self.exclude_mounts, &disk.mount_point)
+    }
+}
+
+/// One line of `/proc/self/mountinfo`.
+#[derive(Debug, Clone)]
+pub(crate) struct MountEntry {
+    pub major: u32,
+    pub minor: u32,
+    pub mount_point: String,
+    pub mount_options: Vec<String>,
+    pub fs_type: String,
+    pub source: String,
+    pub super_options: Vec<String>,
+}
+
+impl MountEntry {
+    pub fn device_id(&self) -> String {
+        format!("{}:{}", self.major, self.minor)
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.mount_options.iter().any(|o| o == "ro") || self.super_options.iter().any(|o| o == "ro")
+    }
 }
 
 impl DisksInfo {
-    pub fn collect() -> Self {
+    pub fn collect(filter: &DiskFilter) -> Self {
         let disks = Disks::new_with_refreshed_list();
-        let disk_list: Vec<DiskInfo> = disks
+        let mounts = read_mountinfo();
+        let mut disk_list: Vec<DiskInfo> = disks
             .iter()
             .map(|disk| {
                 let total = disk.total_space();
@@ -33,19 +235,460 @@ impl DisksInfo {
                     0.0
                 };
 
+                let inodes = Self::inode_usage(disk.mount_point());
+                let used_inodes = inodes.map(|i| i.total.saturating_sub(i.free));
+
+                let mount_point = disk.mount_point().to_string_lossy().to_string();
+                // Later entries shadow earlier ones mounted on the same path
+                let mount = mounts.iter().rev().find(|m| m.mount_point == mount_point);
+
                 DiskInfo {
                     name: disk.name().to_string_lossy().to_string(),
-                    mount_point: disk.mount_point().to_string_lossy().to_string(),
+                    mount_point,
                     total_space: total,
                     available_space: available,
                     used_space: used,
                     usage_percent,
                     file_system: disk.file_system().to_string_lossy().to_string(),
                     is_removable: disk.is_removable(),
+                    total_inodes: inodes.map(|i| i.total),
+                    used_inodes,
+                    free_inodes: inodes.map(|i| i.free),
+                    inode_usage_percent: inodes
+                        .zip(used_inodes)
+                        .map(|(i, used)| (used as f32 / i.total as f32) * 100.0),
+                    source: mount.map(|m| m.source.clone()),
+                    device_id: mount.map(|m| m.device_id()),
+                    mount_options: mount.map(|m| m.mount_options.clone()).unwrap_or_default(),
+                    read_only: mount.is_some_and(|m| m.read_only()),
+                    fill_rate: None,
+                    time_to_full_secs: None,
+                    full_soon: false,
                 }
             })
             .collect();
+        let mounted = disk_list.iter().map(DiskHistory::key).collect();
+        disk_list.retain(|disk| filter.matches(disk));
+
+        Self {
+            disks: disk_list,
+            events: Vec::new(),
+            event_log: Vec::new(),
+            mounted,
+        }
+    }
+
+    #[cfg(unix)]
+    fn inode_usage(mount_point: &Path) -> Option<InodeUsage> {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        // SAFETY: `path` is a valid NUL-terminated string and `stat` is a writable statvfs
+        if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+            return None;
+        }
+
+        // Filesystems without a fixed inode table (btrfs, vfat, ...) report zero inodes
+        if stat.f_files == 0 {
+            return None;
+        }
+
+        Some(InodeUsage {
+            total: stat.f_files as u64,
+            free: stat.f_ffree as u64,
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn inode_usage(_mount_point: &Path) -> Option<InodeUsage> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct UsageSample {
+    /// Seconds since the Unix epoch
+    timestamp: f64,
+    used: u64,
+}
+
+/// Used-space samples per mount point, kept across watch ticks or persisted to a
+/// file between runs, used to project when each filesystem will fill up.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DiskHistory {
+    /// Keyed by "<source> on <mount point>"
+    mounts: HashMap<String, VecDeque<UsageSample>>,
+}
+
+impl DiskHistory {
+    /// Loads history saved by a previous run. A missing file yields an empty history.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes to a temporary file next to `path` and renames it into place, so an
+    /// interrupted run never leaves a truncated history behind.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        let temp = PathBuf::from(temp);
+
+        fs::write(&temp, serde_json::to_string(self)?)?;
+        if let Err(e) = fs::rename(&temp, path) {
+            let _ = fs::remove_file(&temp);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Series are kept per device and mount point, so a different filesystem mounted
+    /// on the same path starts a new series.
+    fn key(disk: &DiskInfo) -> String {
+        format!("{} on {}", disk.source.as_deref().unwrap_or(&disk.name), disk.mount_point)
+    }
+
+    /// Adds the current usage of every disk and fills in its fill rate and projected
+    /// time to full. Mounts expected to fill within `horizon_secs` are flagged.
+    pub fn record(&mut self, info: &mut DisksInfo, horizon_secs: u64) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+
+        // Forget filesystems that are no longer mounted
+        self.mounts.retain(|key, _| info.mounted.contains(key));
+
+        for disk in &mut info.disks {
+            let samples = self.mounts.entry(Self::key(disk)).or_default();
+            samples.push_back(UsageSample {
+                timestamp: now,
+                used: disk.used_space,
+            });
+            while samples.len() > MAX_HISTORY_SAMPLES
+                || samples.front().is_some_and(|s| now - s.timestamp > MAX_HISTORY_AGE_SECS)
+            {
+                samples.pop_front();
+            }
+
+            disk.fill_rate = fill_rate(samples);
+            disk.time_to_full_secs = disk
+                .fill_rate
+                .filter(|rate| *rate > 0.0)
+                .map(|rate| (disk.available_space as f64 / rate) as u64);
+            disk.full_soon = disk.time_to_full_secs.is_some_and(|secs| secs <= horizon_secs);
+        }
+    }
+}
+
+/// Least-squares slope of used space over time, in bytes per second.
+fn fill_rate(samples: &VecDeque<UsageSample>) -> Option<f64> {
+    if samples.len() < 2 {
+        return None;
+    }
+
+    let n = samples.len() as f64;
+    let origin = samples[0].timestamp;
+    let mean_t = samples.iter().map(|s| s.timestamp - origin).sum::<f64>() / n;
+    let mean_u = samples.iter().map(|s| s.used as f64).sum::<f64>() / n;
+
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for sample in samples {
+        let dt = sample.timestamp - origin - mean_t;
+        covariance += dt * (sample.used as f64 - mean_u);
+        variance += dt * dt;
+    }
+
+    if variance > 0.0 {
+        Some(covariance / variance)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct InodeUsage {
+    total: u64,
+    free: u64,
+}
+
+/// Reads `/proc/self/mountinfo`. Returns an empty list where it is unavailable.
+pub(crate) fn read_mountinfo() -> Vec<MountEntry> {
+    match fs::read_to_string("/proc/self/mountinfo") {
+        Ok(content) => parse_mountinfo(&content),
+        Err(_) => vec![],
+    }
+}
+
+fn parse_mountinfo(content: &str) -> Vec<MountEntry> {
+    // id parent major:minor root mount_point options [optional fields...] - fstype source super_options
+    content
+        .lines()
+        .filter_map(|line| {
+            let (left, right) = line.split_once(" - ")?;
+            let left: Vec<&str> = left.split_whitespace().collect();
+            let right: Vec<&str> = right.split_whitespace().collect();
+            if left.len() < 6 || right.len() < 2 {
+                return None;
+            }
+            let (major, minor) = left[2].split_once(':')?;
+
+            Some(MountEntry {
+                major: major.parse().ok()?,
+                minor: minor.parse().ok()?,
+                mount_point: unescape_mount_path(left[4]),
+                mount_options: left[5].split(',').map(String::from).collect(),
+                fs_type: right[0].to_string(),
+                source: unescape_mount_path(right[1]),
+                super_options: right
+                    .get(2)
+                    .map(|o| o.split(',').map(String::from).collect())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Decodes the octal escapes (`\040` for space, ...) the kernel uses in mount tables.
+pub(crate) fn unescape_mount_path(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_escape = bytes[i] == b'\\'
+            && i + 3 < bytes.len()
+            && bytes[i + 1..i + 4].iter().all(|b| (b'0'..=b'7').contains(b));
+        if is_escape {
+            let code = u8::from_str_radix(&raw[i + 1..i + 4], 8).unwrap_or(b'?');
+            out.push(code);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(source: &str, mount_point: &str, used: u64, available: u64) -> DiskInfo {
+        DiskInfo {
+            name: source.to_string(),
+            mount_point: mount_point.to_string(),
+            total_space: used + available,
+            available_space: available,
+            used_space: used,
+            usage_percent: 0.0,
+            file_system: "ext4".to_string(),
+            is_removable: false,
+            total_inodes: None,
+            used_inodes: None,
+            free_inodes: None,
+            inode_usage_percent: None,
+            source: Some(source.to_string()),
+            device_id: None,
+            mount_options: vec!["rw".to_string()],
+            read_only: false,
+            fill_rate: None,
+            time_to_full_secs: None,
+            full_soon: false,
+        }
+    }
+
+    fn disks_info(disks: Vec<DiskInfo>) -> DisksInfo {
+        DisksInfo {
+            mounted: disks.iter().map(DiskHistory::key).collect(),
+            disks,
+            events: Vec::new(),
+            event_log: Vec::new(),
+        }
+    }
+
+    fn samples(points: &[(f64, u64)]) -> VecDeque<UsageSample> {
+        points
+            .iter()
+            .map(|&(timestamp, used)| UsageSample { timestamp, used })
+            .collect()
+    }
+
+    fn now() -> f64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs_f64()
+    }
+
+    #[test]
+    fn fill_rate_follows_the_trend() {
+        // 100 bytes per second, with some noise
+        let steady = samples(&[(0.0, 1_000), (10.0, 2_050), (20.0, 2_950), (30.0, 4_000)]);
+        assert!((fill_rate(&steady).unwrap() - 100.0).abs() < 2.0);
+
+        let shrinking = samples(&[(0.0, 5_000), (60.0, 4_000), (120.0, 3_000)]);
+        assert!((fill_rate(&shrinking).unwrap() + 16.667).abs() < 0.01);
+    }
+
+    #[test]
+    fn fill_rate_needs_a_time_span() {
+        assert_eq!(fill_rate(&samples(&[])), None);
+        assert_eq!(fill_rate(&samples(&[(10.0, 1_000)])), None);
+        assert_eq!(fill_rate(&samples(&[(10.0, 1_000), (10.0, 2_000)])), None);
+    }
+
+    #[test]
+    fn history_projects_time_to_full() {
+        let gib = 1 << 30;
+        let now = now();
+        let mut history = DiskHistory::default();
+        history.mounts.insert(
+            "/dev/sdb1 on /data".to_string(),
+            samples(&[(now - 7200.0, 0), (now - 3600.0, gib)]),
+        );
+        history.mounts.insert(
+            "/dev/sdc1 on /shrinking".to_string(),
+            samples(&[(now - 7200.0, 4 * gib), (now - 3600.0, 3 * gib)]),
+        );
+        history.mounts.insert("/dev/sdz1 on /gone".to_string(), samples(&[(now - 60.0, 1)]));
+
+        let mut info = disks_info(vec![
+            disk("/dev/sdb1", "/data", 2 * gib, 10 * gib),
+            disk("/dev/sdc1", "/shrinking", 2 * gib, 10 * gib),
+            disk("/dev/sdd1", "/new", gib, gib),
+        ]);
+        history.record(&mut info, 24 * 3600);
+
+        // One GiB per hour with ten left: about ten hours
+        let data = &info.disks[0];
+        let hours = data.time_to_full_secs.unwrap() as f64 / 3600.0;
+        assert!((hours - 10.0).abs() < 0.1, "{hours}");
+        assert!(data.full_soon);
+
+        let shrinking = &info.disks[1];
+        assert!(shrinking.fill_rate.unwrap() < 0.0);
+        assert_eq!(shrinking.time_to_full_secs, None);
+        assert!(!shrinking.full_soon);
+
+        // A single sample gives no rate yet
+        assert_eq!(info.disks[2].fill_rate, None);
+        assert!(!history.mounts.contains_key("/dev/sdz1 on /gone"));
+
+        let mut info = disks_info(vec![disk("/dev/sdb1", "/data", 2 * gib, 10 * gib)]);
+        history.record(&mut info, 3600);
+        assert!(!info.disks[0].full_soon);
+    }
+
+    #[test]
+    fn history_restarts_when_the_device_changes() {
+        let now = now();
+        let mut history = DiskHistory::default();
+        history.mounts.insert(
+            "/dev/sdb1 on /backup".to_string(),
+            samples(&[(now - 7200.0, 0), (now - 3600.0, 1_000_000)]),
+        );
+
+        let mut info = disks_info(vec![disk("/dev/sdc1", "/backup", 5_000_000, 1_000)]);
+        history.record(&mut info, 3600);
+
+        assert_eq!(info.disks[0].fill_rate, None);
+        assert_eq!(history.mounts.len(), 1);
+        assert_eq!(history.mounts["/dev/sdc1 on /backup"].len(), 1);
+    }
+
+    /// Turns a mountinfo snapshot into the disk list the watcher sees.
+    fn snapshot(mountinfo: &str) -> DisksInfo {
+        let disks = parse_mountinfo(mountinfo)
+            .into_iter()
+            .map(|mount| DiskInfo {
+                file_system: mount.fs_type.clone(),
+                mount_options: mount.mount_options.clone(),
+                read_only: mount.read_only(),
+                ..disk(&mount.source, &mount.mount_point, 0, 0)
+            })
+            .collect();
+        disks_info(disks)
+    }
+
+    #[test]
+    fn reports_mount_changes_between_ticks() {
+        let before = "\
+22 1 8:2 / / rw,relatime - ext4 /dev/sda2 rw
+30 22 8:17 / /data rw,relatime - xfs /dev/sdb1 rw
+31 22 8:33 / /media/usb rw,nosuid - vfat /dev/sdc1 rw
+";
+        let after = "\
+22 1 8:2 / / rw,relatime - ext4 /dev/sda2 rw
+30 22 8:17 / /data ro,relatime - xfs /dev/sdb1 ro
+40 22 8:49 / /mnt/backup rw,relatime - ext4 /dev/sdd1 rw
+";
+        let mut watcher = MountWatcher::new();
+        let mut first = snapshot(before);
+        watcher.record(&mut first);
+        assert!(first.events.is_empty());
+
+        let mut second = snapshot(after);
+        watcher.record(&mut second);
+        let events: Vec<(MountEventKind, &str)> = second
+            .events
+            .iter()
+            .map(|e| (e.kind, e.mount_point.as_str()))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (MountEventKind::Remounted, "/data"),
+                (MountEventKind::Mounted, "/mnt/backup"),
+                (MountEventKind::Unmounted, "/media/usb"),
+            ]
+        );
+
+        let remount = &second.events[0];
+        assert_eq!(remount.options, vec!["ro", "relatime"]);
+        assert_eq!(remount.previous_options, Some(vec!["rw".to_string(), "relatime".to_string()]));
+        assert_eq!(second.events[2].device, "/dev/sdc1");
+        assert_eq!(second.event_log.len(), 3);
+
+        // Nothing changed since the last tick
+        let mut third = snapshot(after);
+        watcher.record(&mut third);
+        assert!(third.events.is_empty());
+        assert_eq!(third.event_log.len(), 3);
+    }
+
+    #[test]
+    fn unescapes_octal_sequences() {
+        assert_eq!(unescape_mount_path("/mnt/my\\040disk"), "/mnt/my disk");
+        assert_eq!(unescape_mount_path("/mnt/tab\\011end\\134"), "/mnt/tab\tend\\");
+        // Not a complete escape: left as is
+        assert_eq!(unescape_mount_path("/mnt/a\\04"), "/mnt/a\\04");
+        assert_eq!(unescape_mount_path("/mnt/a\\089"), "/mnt/a\\089");
+    }
+
+    #[test]
+    fn parses_mountinfo_lines() {
+        let content = "\
+22 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw,errors=remount-ro
+35 22 0:31 / /mnt/usb\\040stick ro,nosuid master:3 shared:7 - vfat /dev/sdb1 rw,fmask=0022
+garbage line
+";
+        let mounts = parse_mountinfo(content);
+        assert_eq!(mounts.len(), 2);
+
+        assert_eq!(mounts[0].device_id(), "8:2");
+        assert_eq!(mounts[0].mount_point, "/");
+        assert_eq!(mounts[0].fs_type, "ext4");
+        assert_eq!(mounts[0].source, "/dev/sda2");
+        assert_eq!(mounts[0].super_options, vec!["rw", "errors=remount-ro"]);
+        assert!(!mounts[0].read_only());
 
-        Self { disks: disk_list }
+        assert_eq!(mounts[1].mount_point, "/mnt/usb stick");
+        assert_eq!(mounts[1].fs_type, "vfat");
+        assert!(mounts[1].read_only());
     }
 }
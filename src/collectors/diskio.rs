@@ -0,0 +1,206 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::time::{Duration, Instant};
+
+const SECTOR_SIZE: u64 = 512;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskIoEntry {
+    pub device: String,
+    pub major: u32,
+    pub minor: u32,
+    pub reads_per_sec: f64,
+    pub writes_per_sec: f64,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    /// Average time in ms a read request spent queued and being serviced
+    pub read_await_ms: f64,
+    pub write_await_ms: f64,
+    pub service_time_ms: f64,
+    pub queue_depth: f64,
+    pub in_flight: u64,
+    pub utilization_percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskIoInfo {
+    pub devices: Vec<DiskIoEntry>,
+    pub interval_secs: f64,
+    pub error: Option<String>,
+}
+
+/// Raw counters for one device from `/proc/diskstats`.
+#[derive(Debug, Clone, Copy)]
+struct DiskStat {
+    major: u32,
+    minor: u32,
+    reads: u64,
+    sectors_read: u64,
+    read_ms: u64,
+    writes: u64,
+    sectors_written: u64,
+    write_ms: u64,
+    in_flight: u64,
+    io_ms: u64,
+    weighted_io_ms: u64,
+}
+
+#[derive(Debug)]
+struct DiskStatsSnapshot {
+    taken_at: Instant,
+    devices: Vec<(String, DiskStat)>,
+}
+
+/// Keeps the previous `/proc/diskstats` sample so rates can be computed across watch ticks.
+#[derive(Debug, Default)]
+pub struct DiskIoSampler {
+    previous: Option<DiskStatsSnapshot>,
+}
+
+impl DiskIoInfo {
+    pub fn collect() -> Self {
+        DiskIoSampler::new().sample()
+    }
+
+    fn unavailable(error: std::io::Error) -> Self {
+        Self {
+            devices: vec![],
+            interval_secs: 0.0,
+            error: Some(format!("Failed to read /proc/diskstats: {} (Linux only)", error)),
+        }
+    }
+}
+
+impl DiskIoSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns rates since the previous call, or over a short sampling window on the first call.
+    pub fn sample(&mut self) -> DiskIoInfo {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => {
+                let first = match read_diskstats() {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => return DiskIoInfo::unavailable(e),
+                };
+                std::thread::sleep(Duration::from_millis(200));
+                first
+            }
+        };
+
+        let current = match read_diskstats() {
+            Ok(snapshot) => snapshot,
+            Err(e) => return DiskIoInfo::unavailable(e),
+        };
+
+        let elapsed = current.taken_at.duration_since(previous.taken_at);
+        let elapsed_secs = elapsed.as_secs_f64();
+        let elapsed_ms = elapsed_secs * 1000.0;
+        let before: HashMap<&str, &DiskStat> = previous
+            .devices
+            .iter()
+            .map(|(name, stat)| (name.as_str(), stat))
+            .collect();
+
+        let devices = current
+            .devices
+            .iter()
+            // Devices that have never seen I/O (unused loop and ram devices) are only noise
+            .filter(|(_, now)| now.reads + now.writes > 0)
+            .filter_map(|(name, now)| {
+                let then = before.get(name.as_str())?;
+                let reads = now.reads.saturating_sub(then.reads);
+                let writes = now.writes.saturating_sub(then.writes);
+                let io_ms = now.io_ms.saturating_sub(then.io_ms);
+
+                Some(DiskIoEntry {
+                    device: name.clone(),
+                    major: now.major,
+                    minor: now.minor,
+                    reads_per_sec: per_sec(reads as f64, elapsed_secs),
+                    writes_per_sec: per_sec(writes as f64, elapsed_secs),
+                    read_bytes_per_sec: per_sec(
+                        (now.sectors_read.saturating_sub(then.sectors_read) * SECTOR_SIZE) as f64,
+                        elapsed_secs,
+                    ),
+                    write_bytes_per_sec: per_sec(
+                        (now.sectors_written.saturating_sub(then.sectors_written) * SECTOR_SIZE) as f64,
+                        elapsed_secs,
+                    ),
+                    read_await_ms: average(now.read_ms.saturating_sub(then.read_ms), reads),
+                    write_await_ms: average(now.write_ms.saturating_sub(then.write_ms), writes),
+                    service_time_ms: average(io_ms, reads + writes),
+                    queue_depth: per_sec(
+                        now.weighted_io_ms.saturating_sub(then.weighted_io_ms) as f64,
+                        elapsed_ms,
+                    ),
+                    in_flight: now.in_flight,
+                    utilization_percent: (per_sec(io_ms as f64, elapsed_ms) * 100.0).min(100.0),
+                })
+            })
+            .collect();
+
+        self.previous = Some(current);
+
+        DiskIoInfo {
+            devices,
+            interval_secs: elapsed_secs,
+            error: None,
+        }
+    }
+}
+
+fn read_diskstats() -> Result<DiskStatsSnapshot, std::io::Error> {
+    let content = fs::read_to_string("/proc/diskstats")?;
+    let taken_at = Instant::now();
+
+    // major minor name reads reads_merged sectors_read read_ms writes writes_merged
+    // sectors_written write_ms in_flight io_ms weighted_io_ms [discard and flush fields]
+    let devices = content
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 14 {
+                return None;
+            }
+            let field = |i: usize| parts[i].parse::<u64>().ok();
+            Some((
+                parts[2].to_string(),
+                DiskStat {
+                    major: parts[0].parse().ok()?,
+                    minor: parts[1].parse().ok()?,
+                    reads: field(3)?,
+                    sectors_read: field(5)?,
+                    read_ms: field(6)?,
+                    writes: field(7)?,
+                    sectors_written: field(9)?,
+                    write_ms: field(10)?,
+                    in_flight: field(11)?,
+                    io_ms: field(12)?,
+                    weighted_io_ms: field(13)?,
+                },
+            ))
+        })
+        .collect();
+
+    Ok(DiskStatsSnapshot { taken_at, devices })
+}
+
+fn per_sec(value: f64, elapsed: f64) -> f64 {
+    if elapsed > 0.0 {
+        value / elapsed
+    } else {
+        0.0
+    }
+}
+
+fn average(total_ms: u64, count: u64) -> f64 {
+    if count > 0 {
+        total_ms as f64 / count as f64
+    } else {
+        0.0
+    }
+}
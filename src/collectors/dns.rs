@@ -0,0 +1,536 @@
+use serde::Serialize;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6, UdpSocket};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::hosts::HostsInfo;
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+const NSSWITCH_CONF: &str = "/etc/nsswitch.conf";
+/// Upstream servers systemd-resolved forwards to, when it runs the local stub
+const RESOLVED_UPSTREAM_CONF: &str = "/run/systemd/resolve/resolv.conf";
+const RESOLVED_STUB_ADDRESS: &str = "127.0.0.53";
+
+const DNS_TIMEOUT: Duration = Duration::from_secs(2);
+const TYPE_A: u16 = 1;
+const TYPE_AAAA: u16 = 28;
+
+/// Addresses and the nameserver that supplied them, `None` when the source has no answer
+type LookupResult = Result<Option<(Vec<IpAddr>, Option<String>)>, String>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedStub {
+    /// The servers systemd-resolved itself queries
+    pub upstream_nameservers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolutionStep {
+    /// nsswitch source, e.g. `files` or `dns`
+    pub source: String,
+    /// found, not found, unsupported, or an error message
+    pub outcome: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NameResolution {
+    pub name: String,
+    pub addresses: Vec<IpAddr>,
+    /// nsswitch source that produced the answer
+    pub answered_by: Option<String>,
+    /// Nameserver that answered, for DNS answers
+    pub server: Option<String>,
+    pub elapsed_ms: f64,
+    pub steps: Vec<ResolutionStep>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DnsInfo {
+    pub nameservers: Vec<String>,
+    pub search_domains: Vec<String>,
+    pub options: Vec<String>,
+    /// Sources from the `hosts:` line of nsswitch.conf, in lookup order
+    pub hosts_sources: Vec<String>,
+    /// Set when resolv.conf points at the systemd-resolved stub listener
+    pub systemd_resolved: Option<ResolvedStub>,
+    pub resolution: Option<NameResolution>,
+    pub error: Option<String>,
+}
+
+/// Parsed `resolv.conf` contents.
+#[derive(Debug, Default)]
+struct ResolvConf {
+    nameservers: Vec<String>,
+    search_domains: Vec<String>,
+    options: Vec<String>,
+}
+
+impl DnsInfo {
+    pub fn collect(resolve: Option<&str>) -> Self {
+        // Without resolv.conf the other nsswitch sources still work, so keep going
+        let (resolv, error) = match fs::read_to_string(RESOLV_CONF) {
+            Ok(content) => (parse_resolv_conf(&content), None),
+            Err(e) => (ResolvConf::default(), Some(format!("Failed to read {}: {}", RESOLV_CONF, e))),
+        };
+
+        // glibc falls back to "files dns" when nsswitch.conf has no hosts line
+        let hosts_sources = fs::read_to_string(NSSWITCH_CONF)
+            .ok()
+            .and_then(|content| parse_nsswitch_hosts(&content))
+            .unwrap_or_else(|| vec!["files".to_string(), "dns".to_string()]);
+
+        let stub_link = fs::read_link(RESOLV_CONF)
+            .map(|target| target.to_string_lossy().contains("systemd/resolve"))
+            .unwrap_or(false);
+        let systemd_resolved = (stub_link || resolv.nameservers.iter().any(|n| n == RESOLVED_STUB_ADDRESS)).then(|| {
+            ResolvedStub {
+                upstream_nameservers: fs::read_to_string(RESOLVED_UPSTREAM_CONF)
+                    .map(|content| parse_resolv_conf(&content).nameservers)
+                    .unwrap_or_default(),
+            }
+        });
+
+        let resolution = resolve.map(|name| resolve_name(name, &hosts_sources, &resolv));
+
+        Self {
+            nameservers: resolv.nameservers,
+            search_domains: resolv.search_domains,
+            options: resolv.options,
+            hosts_sources,
+            systemd_resolved,
+            resolution,
+            error,
+        }
+    }
+}
+
+fn parse_resolv_conf(content: &str) -> ResolvConf {
+    let mut conf = ResolvConf::default();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("nameserver") => conf.nameservers.extend(parts.next().map(String::from)),
+            // The last search or domain line wins
+            Some("search") | Some("domain") => conf.search_domains = parts.map(String::from).collect(),
+            Some("options") => conf.options.extend(parts.map(String::from)),
+            _ => {}
+        }
+    }
+
+    conf
+}
+
+/// Returns the `hosts:` sources, dropping `[NOTFOUND=return]` style actions.
+fn parse_nsswitch_hosts(content: &str) -> Option<Vec<String>> {
+    let line = content
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .find(|l| l.starts_with("hosts:"))?;
+
+    let mut sources = Vec::new();
+    let mut in_action = false;
+    for token in line["hosts:".len()..].split_whitespace() {
+        if token.starts_with('[') || in_action {
+            in_action = !token.ends_with(']');
+        } else {
+            sources.push(token.to_string());
+        }
+    }
+
+    Some(sources)
+}
+
+/// Walks the nsswitch sources in order and stops at the first that answers.
+fn resolve_name(name: &str, sources: &[String], resolv: &ResolvConf) -> NameResolution {
+    let started = Instant::now();
+    let mut steps = Vec::new();
+
+    for source in sources {
+        let answer: LookupResult = match source.as_str() {
+            "files" => Ok(lookup_hosts_file(name).map(|addresses| (addresses, None))),
+            "myhostname" => Ok(lookup_myhostname(name).map(|addresses| (addresses, None))),
+            // systemd-resolved answers on its stub address
+            "dns" | "resolve" => {
+                let servers = if source == "resolve" {
+                    vec![RESOLVED_STUB_ADDRESS.to_string()]
+                } else {
+                    resolv.nameservers.clone()
+                };
+                lookup_dns(name, &servers, &resolv.search_domains)
+            }
+            _ => {
+                steps.push(ResolutionStep {
+                    source: source.clone(),
+                    outcome: "unsupported".to_string(),
+                });
+                continue;
+            }
+        };
+
+        match answer {
+            Ok(Some((addresses, server))) => {
+                steps.push(ResolutionStep {
+                    source: source.clone(),
+                    outcome: "found".to_string(),
+                });
+                return NameResolution {
+                    name: name.to_string(),
+                    addresses,
+                    answered_by: Some(source.clone()),
+                    server,
+                    elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+                    steps,
+                };
+            }
+            Ok(None) => steps.push(ResolutionStep {
+                source: source.clone(),
+                outcome: "not found".to_string(),
+            }),
+            Err(e) => steps.push(ResolutionStep {
+                source: source.clone(),
+                outcome: e,
+            }),
+        }
+    }
+
+    NameResolution {
+        name: name.to_string(),
+        addresses: vec![],
+        answered_by: None,
+        server: None,
+        elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+        steps,
+    }
+}
+
+fn lookup_hosts_file(name: &str) -> Option<Vec<IpAddr>> {
+    let hosts = HostsInfo::collect(true);
+    let addresses: Vec<IpAddr> = hosts
+        .entries
+        .iter()
+        .filter(|entry| entry.hostnames.iter().any(|h| h.eq_ignore_ascii_case(name)))
+        .filter_map(|entry| entry.ip.parse().ok())
+        .collect();
+    (!addresses.is_empty()).then_some(addresses)
+}
+
+/// nss-myhostname: the local hostname and `localhost` names resolve to loopback.
+fn lookup_myhostname(name: &str) -> Option<Vec<IpAddr>> {
+    let hostname = fs::read_to_string(Path::new("/proc/sys/kernel/hostname")).unwrap_or_default();
+    let name = name.trim_end_matches('.').to_ascii_lowercase();
+    let is_local = name == "localhost"
+        || name.ends_with(".localhost")
+        || name.eq_ignore_ascii_case(hostname.trim());
+    is_local.then(|| vec![IpAddr::V4(Ipv4Addr::LOCALHOST), IpAddr::V6(Ipv6Addr::LOCALHOST)])
+}
+
+/// Queries each nameserver in turn for A and AAAA records. Single-label names are
+/// tried with the search domains first, like the resolver does with `ndots:1`.
+fn lookup_dns(
+    name: &str,
+    servers: &[String],
+    search_domains: &[String],
+) -> LookupResult {
+    if servers.is_empty() {
+        return Err("no nameservers configured".to_string());
+    }
+
+    let name = name.trim_end_matches('.');
+    let mut candidates: Vec<String> = Vec::new();
+    if !name.contains('.') {
+        candidates.extend(search_domains.iter().map(|domain| format!("{}.{}", name, domain)));
+    }
+    candidates.push(name.to_string());
+
+    let mut last_error = None;
+    for server in servers {
+        let address = match server_address(server) {
+            Ok(address) => address,
+            Err(e) => {
+                last_error = Some(format!("{}: {}", server, e));
+                continue;
+            }
+        };
+
+        let mut server_error = None;
+        for candidate in &candidates {
+            let mut addresses = Vec::new();
+            for record_type in [TYPE_A, TYPE_AAAA] {
+                match query(address, candidate, record_type) {
+                    Ok(found) => addresses.extend(found),
+                    Err(e) => server_error = Some(format!("{}: {}", server, e)),
+                }
+            }
+            if !addresses.is_empty() {
+                return Ok(Some((addresses, Some(server.clone()))));
+            }
+        }
+
+        // The server answered, just without records; later servers are only for failover
+        if server_error.is_none() {
+            return Ok(None);
+        }
+        last_error = server_error;
+    }
+
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
+
+/// Turns a resolv.conf nameserver into a socket address. Link-local IPv6 servers may
+/// carry a `%zone` suffix naming the interface, which becomes the scope id.
+fn server_address(server: &str) -> Result<SocketAddr, String> {
+    let (host, zone) = match server.split_once('%') {
+        Some((host, zone)) => (host, Some(zone)),
+        None => (server, None),
+    };
+    let ip = host.parse::<IpAddr>().map_err(|_| "invalid address".to_string())?;
+
+    match (ip, zone) {
+        (IpAddr::V6(ip), Some(zone)) => {
+            let scope_id = match zone.parse::<u32>() {
+                Ok(index) => index,
+                Err(_) => interface_index(zone)
+                    .ok_or_else(|| format!("unknown interface {}", zone))?,
+            };
+            Ok(SocketAddr::V6(SocketAddrV6::new(ip, 53, 0, scope_id)))
+        }
+        (IpAddr::V4(_), Some(_)) => Err("zone on an IPv4 address".to_string()),
+        (ip, None) => Ok(SocketAddr::new(ip, 53)),
+    }
+}
+
+#[cfg(unix)]
+fn interface_index(name: &str) -> Option<u32> {
+    let name = std::ffi::CString::new(name).ok()?;
+    // SAFETY: name is a valid NUL-terminated string that outlives the call
+    let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+    (index != 0).then_some(index)
+}
+
+#[cfg(not(unix))]
+fn interface_index(_name: &str) -> Option<u32> {
+    None
+}
+
+/// Sends one DNS query over UDP and returns the addresses in the answer section.
+fn query(server: SocketAddr, name: &str, record_type: u16) -> Result<Vec<IpAddr>, String> {
+    let bind = if server.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(bind).map_err(|e| e.to_string())?;
+
+    let id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u16)
+        .unwrap_or(0x5745);
+
+    // Header: id, flags (recursion desired), one question, no other records
+    let mut packet = Vec::with_capacity(64);
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&[0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    for label in name.split('.').filter(|l| !l.is_empty()) {
+        if label.len() > 63 {
+            return Err("label too long".to_string());
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&record_type.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+
+    socket.send_to(&packet, server).map_err(|e| e.to_string())?;
+    let deadline = Instant::now() + DNS_TIMEOUT;
+    let mut buf = [0u8; 1500];
+
+    // Late answers to earlier queries, or stray packets, carry another id; skip them
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err("timed out".to_string());
+        }
+        socket.set_read_timeout(Some(remaining)).map_err(|e| e.to_string())?;
+        let (len, from) = socket.recv_from(&mut buf).map_err(|e| match e.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => "timed out".to_string(),
+            _ => e.to_string(),
+        })?;
+
+        if from != server || len < 2 || u16::from_be_bytes([buf[0], buf[1]]) != id {
+            continue;
+        }
+        return parse_response(&buf[..len], record_type).ok_or_else(|| "malformed response".to_string())?;
+    }
+}
+
+/// Returns the A/AAAA addresses of a response; NXDOMAIN is an empty answer, other
+/// error codes and truncated responses are errors.
+fn parse_response(data: &[u8], record_type: u16) -> Option<Result<Vec<IpAddr>, String>> {
+    let read_u16 = |pos: usize| -> Option<u16> { Some(u16::from_be_bytes([*data.get(pos)?, *data.get(pos + 1)?])) };
+
+    let flags = read_u16(2)?;
+    // TC: the answer did not fit in UDP and would need a retry over TCP
+    if flags & 0x0200 != 0 {
+        return Some(Err("truncated response".to_string()));
+    }
+    match flags & 0x000F {
+        0 | 3 => {}
+        2 => return Some(Err("server failure".to_string())),
+        5 => return Some(Err("refused".to_string())),
+        code => return Some(Err(format!("rcode {}", code))),
+    }
+    let questions = read_u16(4)?;
+    let answers = read_u16(6)?;
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = skip_name(data, pos)? + 4;
+    }
+
+    let mut addresses = Vec::new();
+    for _ in 0..answers {
+        pos = skip_name(data, pos)?;
+        let rtype = read_u16(pos)?;
+        let rdlength = read_u16(pos + 8)? as usize;
+        let rdata = data.get(pos + 10..pos + 10 + rdlength)?;
+        pos += 10 + rdlength;
+
+        if rtype != record_type {
+            // CNAME records precede the addresses they point to
+            continue;
+        }
+        match rdata.len() {
+            4 => addresses.push(IpAddr::V4(Ipv4Addr::new(rdata[0], rdata[1], rdata[2], rdata[3]))),
+            16 => {
+                let octets: [u8; 16] = rdata.try_into().ok()?;
+                addresses.push(IpAddr::V6(Ipv6Addr::from(octets)));
+            }
+            _ => {}
+        }
+    }
+
+    Some(Ok(addresses))
+}
+
+/// Returns the offset just past a possibly compressed domain name.
+fn skip_name(data: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        let len = *data.get(pos)? as usize;
+        if len == 0 {
+            return Some(pos + 1);
+        }
+        if len & 0xC0 == 0xC0 {
+            return Some(pos + 2);
+        }
+        pos += len + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Response header plus the question for `example.com`, type `qtype`.
+    fn response(flags: u16, answers: u16, qtype: u16) -> Vec<u8> {
+        let mut data = vec![0x12, 0x34];
+        data.extend_from_slice(&flags.to_be_bytes());
+        data.extend_from_slice(&[0x00, 0x01]);
+        data.extend_from_slice(&answers.to_be_bytes());
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(b"\x07example\x03com\x00");
+        data.extend_from_slice(&qtype.to_be_bytes());
+        data.extend_from_slice(&[0x00, 0x01]);
+        data
+    }
+
+    /// Answer record with a compressed owner name pointing at the question.
+    fn record(rtype: u16, rdata: &[u8]) -> Vec<u8> {
+        let mut data = vec![0xC0, 0x0C];
+        data.extend_from_slice(&rtype.to_be_bytes());
+        data.extend_from_slice(&[0x00, 0x01, 0x00, 0x00, 0x0E, 0x10]);
+        data.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        data.extend_from_slice(rdata);
+        data
+    }
+
+    #[test]
+    fn decodes_address_records_after_cname() {
+        let mut data = response(0x8180, 2, TYPE_A);
+        data.extend(record(5, b"\x03www\xC0\x0C"));
+        data.extend(record(TYPE_A, &[93, 184, 216, 34]));
+        assert_eq!(
+            parse_response(&data, TYPE_A),
+            Some(Ok(vec!["93.184.216.34".parse().unwrap()]))
+        );
+
+        let mut data = response(0x8180, 1, TYPE_AAAA);
+        let v6: Ipv6Addr = "2606:2800:220:1::".parse().unwrap();
+        data.extend(record(TYPE_AAAA, &v6.octets()));
+        assert_eq!(parse_response(&data, TYPE_AAAA), Some(Ok(vec![IpAddr::V6(v6)])));
+    }
+
+    #[test]
+    fn reports_error_codes_and_truncation() {
+        assert_eq!(parse_response(&response(0x8183, 0, TYPE_A), TYPE_A), Some(Ok(vec![])));
+        assert_eq!(
+            parse_response(&response(0x8182, 0, TYPE_A), TYPE_A),
+            Some(Err("server failure".to_string()))
+        );
+        assert_eq!(
+            parse_response(&response(0x8380, 0, TYPE_A), TYPE_A),
+            Some(Err("truncated response".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_cut_off_records() {
+        let mut data = response(0x8180, 1, TYPE_A);
+        data.extend(record(TYPE_A, &[10, 0, 0, 1]));
+        data.truncate(data.len() - 2);
+        assert_eq!(parse_response(&data, TYPE_A), None);
+    }
+
+    #[test]
+    fn keeps_the_zone_of_link_local_servers() {
+        let address = server_address("10.0.0.53").unwrap();
+        assert_eq!(address, "10.0.0.53:53".parse().unwrap());
+
+        match server_address("fe80::1%3").unwrap() {
+            SocketAddr::V6(v6) => {
+                assert_eq!(*v6.ip(), "fe80::1".parse::<Ipv6Addr>().unwrap());
+                assert_eq!(v6.port(), 53);
+                assert_eq!(v6.scope_id(), 3);
+            }
+            other => panic!("expected an IPv6 address, got {}", other),
+        }
+
+        assert!(server_address("fe80::1%no-such-interface0").is_err());
+        assert!(server_address("10.0.0.53%eth0").is_err());
+        assert!(server_address("resolver").is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn resolves_interface_names_to_scope_ids() {
+        match server_address("fe80::1%lo").unwrap() {
+            SocketAddr::V6(v6) => assert_eq!(v6.scope_id(), interface_index("lo").unwrap()),
+            other => panic!("expected an IPv6 address, got {}", other),
+        }
+    }
+
+    #[test]
+    fn parses_resolv_conf() {
+        let conf = parse_resolv_conf(
+            "# generated\nnameserver 10.0.0.53\nnameserver fe80::1%eth0\ndomain old.example\nsearch corp.example lab.example\noptions ndots:2 edns0\n",
+        );
+        assert_eq!(conf.nameservers, vec!["10.0.0.53", "fe80::1%eth0"]);
+        assert_eq!(conf.search_domains, vec!["corp.example", "lab.example"]);
+        assert_eq!(conf.options, vec!["ndots:2", "edns0"]);
+    }
+}
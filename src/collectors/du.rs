@@ -0,0 +1,336 @@
+use serde::Serialize;
+#[cfg(unix)]
+use std::collections::HashSet;
+use std::fs::{self, Metadata};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+#[derive(Debug, Clone)]
+pub struct DuOptions {
+    pub path: PathBuf,
+    /// How many levels below the root to keep in the result; sizes always cover the full tree
+    pub max_depth: usize,
+    /// Keep only the N largest entries per directory
+    pub top: Option<usize>,
+    pub one_file_system: bool,
+    pub tree: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuEntry {
+    pub path: String,
+    pub name: String,
+    pub is_dir: bool,
+    /// Space allocated on disk, like `du`
+    pub size: u64,
+    pub file_count: u64,
+    pub children: Vec<DuEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuInfo {
+    pub root: DuEntry,
+    pub errors: u64,
+}
+
+/// Directory entries paired with their (not followed) metadata
+type EntryList = Vec<(PathBuf, Metadata)>;
+
+/// State shared by the scanning threads.
+struct Scan {
+    root_device: Option<u64>,
+    one_file_system: bool,
+    max_depth: usize,
+    top: Option<usize>,
+    /// Hard-linked files already counted, by (device, inode)
+    #[cfg(unix)]
+    seen_links: Mutex<HashSet<(u64, u64)>>,
+    errors: Mutex<u64>,
+}
+
+impl DuInfo {
+    pub fn collect(options: &DuOptions) -> std::io::Result<Self> {
+        let metadata = fs::symlink_metadata(&options.path)?;
+        let scan = Scan {
+            root_device: device_id(&metadata),
+            one_file_system: options.one_file_system,
+            max_depth: options.max_depth,
+            top: options.top,
+            #[cfg(unix)]
+            seen_links: Mutex::new(HashSet::new()),
+            errors: Mutex::new(0),
+        };
+
+        let root = if metadata.is_dir() {
+            scan.scan_root(&options.path, &metadata)
+        } else {
+            scan.file_entry(&options.path, &metadata)
+        };
+
+        let errors = *scan.errors.lock().unwrap();
+        Ok(Self { root, errors })
+    }
+}
+
+impl Scan {
+    /// Scans the root's subdirectories on a pool of threads, one per CPU. Workers take the
+    /// next top-level directory from a shared queue, so one large directory does not hold
+    /// up the others; everything below a top-level directory is scanned by a single thread.
+    fn scan_root(&self, path: &Path, metadata: &Metadata) -> DuEntry {
+        let (files, dirs) = self.read_children(path);
+        let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+        let next = AtomicUsize::new(0);
+
+        let mut children: Vec<DuEntry> = thread::scope(|s| {
+            let handles: Vec<_> = (0..workers.min(dirs.len()).max(1))
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut scanned = Vec::new();
+                        while let Some((dir, meta)) = dirs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            scanned.push(self.scan_dir(dir, meta, 1));
+                        }
+                        scanned
+                    })
+                })
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap_or_default()).collect()
+        });
+
+        children.extend(files.iter().map(|(file, meta)| self.file_entry(file, meta)));
+        self.finish_dir(path, metadata, children, 0)
+    }
+
+    fn scan_dir(&self, path: &Path, metadata: &Metadata, depth: usize) -> DuEntry {
+        let (files, dirs) = self.read_children(path);
+        let mut children: Vec<DuEntry> = dirs
+            .iter()
+            .map(|(dir, meta)| self.scan_dir(dir, meta, depth + 1))
+            .collect();
+        children.extend(files.iter().map(|(file, meta)| self.file_entry(file, meta)));
+        self.finish_dir(path, metadata, children, depth)
+    }
+
+    /// Totals the children into the directory entry, then prunes them to the depth and top-N limits.
+    fn finish_dir(&self, path: &Path, metadata: &Metadata, mut children: Vec<DuEntry>, depth: usize) -> DuEntry {
+        let size = allocated_size(metadata) + children.iter().map(|c| c.size).sum::<u64>();
+        let file_count = children.iter().map(|c| c.file_count).sum();
+
+        if depth >= self.max_depth {
+            children.clear();
+        } else {
+            children.sort_by_key(|c| std::cmp::Reverse(c.size));
+            if let Some(n) = self.top {
+                children.truncate(n);
+            }
+        }
+
+        DuEntry {
+            path: path.to_string_lossy().to_string(),
+            name: entry_name(path),
+            is_dir: true,
+            size,
+            file_count,
+            children,
+        }
+    }
+
+    fn file_entry(&self, path: &Path, metadata: &Metadata) -> DuEntry {
+        DuEntry {
+            path: path.to_string_lossy().to_string(),
+            name: entry_name(path),
+            is_dir: false,
+            size: if self.first_link(metadata) { allocated_size(metadata) } else { 0 },
+            file_count: 1,
+            children: vec![],
+        }
+    }
+
+    /// Lists a directory without following symlinks, split into files and subdirectories.
+    fn read_children(&self, path: &Path) -> (EntryList, EntryList) {
+        let mut files = Vec::new();
+        let mut dirs = Vec::new();
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => {
+                *self.errors.lock().unwrap() += 1;
+                return (files, dirs);
+            }
+        };
+
+        for entry in entries {
+            let Ok(entry) = entry else {
+                *self.errors.lock().unwrap() += 1;
+                continue;
+            };
+            let child = entry.path();
+            let Ok(metadata) = fs::symlink_metadata(&child) else {
+                *self.errors.lock().unwrap() += 1;
+                continue;
+            };
+
+            if metadata.is_dir() {
+                if self.one_file_system && device_id(&metadata) != self.root_device {
+                    continue;
+                }
+                dirs.push((child, metadata));
+            } else {
+                files.push((child, metadata));
+            }
+        }
+
+        (files, dirs)
+    }
+
+    #[cfg(unix)]
+    fn first_link(&self, metadata: &Metadata) -> bool {
+        use std::os::unix::fs::MetadataExt;
+
+        if metadata.nlink() <= 1 {
+            return true;
+        }
+        self.seen_links.lock().unwrap().insert((metadata.dev(), metadata.ino()))
+    }
+
+    #[cfg(not(unix))]
+    fn first_link(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+}
+
+fn entry_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+#[cfg(unix)]
+fn allocated_size(metadata: &Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated_size(metadata: &Metadata) -> u64 {
+    metadata.len()
+}
+
+#[cfg(unix)]
+fn device_id(metadata: &Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn device_id(_metadata: &Metadata) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a fresh directory under the system temp dir for one test.
+    fn temp_tree(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("weni-du-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_file(path: &Path, len: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; len]).unwrap();
+    }
+
+    /// root/{big/{a.bin, nested/b.bin}, medium/c.bin, small/d.bin, top.txt}
+    fn sample_tree(name: &str) -> PathBuf {
+        let root = temp_tree(name);
+        write_file(&root.join("big/a.bin"), 256 * 1024);
+        write_file(&root.join("big/nested/b.bin"), 64 * 1024);
+        write_file(&root.join("medium/c.bin"), 128 * 1024);
+        write_file(&root.join("small/d.bin"), 16 * 1024);
+        write_file(&root.join("top.txt"), 10);
+        root
+    }
+
+    fn options(path: &Path, max_depth: usize, top: Option<usize>) -> DuOptions {
+        DuOptions {
+            path: path.to_path_buf(),
+            max_depth,
+            top,
+            one_file_system: false,
+            tree: false,
+        }
+    }
+
+    fn names(entry: &DuEntry) -> Vec<&str> {
+        entry.children.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn prunes_to_depth_and_top() {
+        let root = sample_tree("prune");
+
+        let info = DuInfo::collect(&options(&root, 1, None)).unwrap();
+        assert_eq!(info.errors, 0);
+        assert_eq!(info.root.file_count, 5);
+        assert_eq!(names(&info.root), vec!["big", "medium", "small", "top.txt"]);
+        // Sizes cover the whole tree even where children are not listed
+        let big = &info.root.children[0];
+        assert!(big.children.is_empty());
+        assert_eq!(big.file_count, 2);
+        let own_size = allocated_size(&fs::symlink_metadata(&root).unwrap());
+        let children_size: u64 = info.root.children.iter().map(|c| c.size).sum();
+        assert_eq!(info.root.size, own_size + children_size);
+
+        let info = DuInfo::collect(&options(&root, 2, Some(2))).unwrap();
+        assert_eq!(names(&info.root), vec!["big", "medium"]);
+        assert_eq!(names(&info.root.children[0]), vec!["a.bin", "nested"]);
+        assert!(info.root.children[0].children[1].children.is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stays_on_one_file_system() {
+        let root = sample_tree("one-fs");
+        let metadata = fs::symlink_metadata(&root).unwrap();
+        // Pretend the root lives on another device, so every subdirectory is a mount
+        let scan = Scan {
+            root_device: device_id(&metadata).map(|dev| dev.wrapping_add(1)),
+            one_file_system: true,
+            max_depth: 1,
+            top: None,
+            seen_links: Mutex::new(HashSet::new()),
+            errors: Mutex::new(0),
+        };
+
+        let entry = scan.scan_root(&root, &metadata);
+        assert_eq!(names(&entry), vec!["top.txt"]);
+        assert_eq!(entry.file_count, 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn counts_hard_links_once() {
+        let root = temp_tree("links");
+        write_file(&root.join("data/original.bin"), 64 * 1024);
+        fs::hard_link(root.join("data/original.bin"), root.join("data/link.bin")).unwrap();
+
+        let info = DuInfo::collect(&options(&root, 2, None)).unwrap();
+        let data = &info.root.children[0];
+        assert_eq!(data.file_count, 2);
+        let sizes: Vec<u64> = data.children.iter().map(|c| c.size).collect();
+        assert_eq!(sizes.iter().filter(|&&size| size == 0).count(), 1);
+        let file_size = allocated_size(&fs::metadata(root.join("data/original.bin")).unwrap());
+        assert_eq!(sizes.iter().sum::<u64>(), file_size);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,371 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use super::disk::{read_mountinfo, unescape_mount_path, DisksInfo, MountEntry};
+
+const FSTAB: &str = "/etc/fstab";
+const SYSTEMD_UNIT_DIR: &str = "/etc/systemd/system";
+
+/// fstab options that only steer `mount` or systemd and never show up in the mount table
+const USERSPACE_OPTIONS: &[&str] = &[
+    "defaults", "auto", "noauto", "user", "users", "nouser", "owner", "group", "nofail",
+    "_netdev", "sw", "loop", "bind", "rbind", "umask",
+];
+
+/// Kernel defaults that are implied when a filesystem is mounted without the negated option
+const IMPLIED_OPTIONS: &[&str] = &["async", "suid", "dev", "exec", "atime", "diratime"];
+
+/// Options whose values the kernel rewrites (`size=10m` becomes `size=10240k`,
+/// user names become ids), so only their presence is compared
+const NORMALIZED_OPTIONS: &[&str] = &["size", "nr_inodes", "mode", "uid", "gid"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MountAuditStatus {
+    Mounted,
+    /// Mounted, but with a different filesystem type or options than configured
+    Mismatch,
+    /// Expected at boot but not mounted
+    Failed,
+    /// Marked `noauto` or a disabled mount unit, so not being mounted is fine
+    NotAutomatic,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MountAuditEntry {
+    /// `/etc/fstab` or the path of the systemd mount unit
+    pub origin: String,
+    pub spec: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub options: Vec<String>,
+    pub nofail: bool,
+    pub status: MountAuditStatus,
+    pub mounted_source: Option<String>,
+    pub mounted_fs_type: Option<String>,
+    pub differences: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnlistedMount {
+    pub mount_point: String,
+    pub device: String,
+    pub file_system: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MountAuditInfo {
+    pub entries: Vec<MountAuditEntry>,
+    /// Mounted disks that neither fstab nor a mount unit accounts for
+    pub unlisted: Vec<UnlistedMount>,
+    pub error: Option<String>,
+}
+
+/// A mount as configured, before it is compared with the live mount table.
+struct ConfiguredMount {
+    origin: String,
+    spec: String,
+    mount_point: String,
+    fs_type: String,
+    options: Vec<String>,
+    automatic: bool,
+}
+
+impl MountAuditInfo {
+    pub fn collect(disks: &DisksInfo) -> Self {
+        if !cfg!(target_os = "linux") {
+            return Self {
+                entries: vec![],
+                unlisted: vec![],
+                error: Some("Mount auditing is only available on Linux".to_string()),
+            };
+        }
+
+        let mut configured = match fs::read_to_string(FSTAB) {
+            Ok(content) => parse_fstab(&content),
+            Err(e) => {
+                return Self {
+                    entries: vec![],
+                    unlisted: vec![],
+                    error: Some(format!("Failed to read {}: {}", FSTAB, e)),
+                }
+            }
+        };
+
+        // fstab wins when both configure the same mount point
+        let known: HashSet<String> = configured.iter().map(|m| m.mount_point.clone()).collect();
+        configured.extend(
+            read_mount_units(Path::new(SYSTEMD_UNIT_DIR))
+                .into_iter()
+                .filter(|m| !known.contains(&m.mount_point)),
+        );
+
+        // Later lines overmount earlier ones, so the last entry per mount point is the visible one
+        let live: HashMap<String, MountEntry> = read_mountinfo()
+            .into_iter()
+            .map(|m| (m.mount_point.clone(), m))
+            .collect();
+
+        let entries: Vec<MountAuditEntry> = configured
+            .into_iter()
+            .map(|m| {
+                let mounted = live.get(&m.mount_point);
+                Self::audit(m, mounted)
+            })
+            .collect();
+
+        let expected: HashSet<&str> = entries.iter().map(|e| e.mount_point.as_str()).collect();
+        let unlisted = disks
+            .disks
+            .iter()
+            // Removable media is mounted on demand and is not expected in fstab
+            .filter(|d| !d.is_removable && !expected.contains(d.mount_point.as_str()))
+            .map(|d| UnlistedMount {
+                mount_point: d.mount_point.clone(),
+                device: d.name.clone(),
+                file_system: d.file_system.clone(),
+            })
+            .collect();
+
+        Self {
+            entries,
+            unlisted,
+            error: None,
+        }
+    }
+
+    pub fn has_failed_mounts(&self) -> bool {
+        self.entries.iter().any(|e| e.status == MountAuditStatus::Failed)
+    }
+
+    fn audit(mount: ConfiguredMount, live: Option<&MountEntry>) -> MountAuditEntry {
+        let nofail = mount.options.iter().any(|o| o == "nofail");
+        let mut differences = Vec::new();
+
+        let status = match live {
+            None if mount.automatic => MountAuditStatus::Failed,
+            None => MountAuditStatus::NotAutomatic,
+            Some(live) => {
+                if !matches!(mount.fs_type.as_str(), "auto" | "none" | "") && live.fs_type != mount.fs_type {
+                    differences.push(format!("type {}, expected {}", live.fs_type, mount.fs_type));
+                }
+                // Bind mounts inherit their options from the source
+                if !mount.options.iter().any(|o| o == "bind" || o == "rbind") {
+                    differences.extend(option_differences(&mount.options, live));
+                }
+                if differences.is_empty() {
+                    MountAuditStatus::Mounted
+                } else {
+                    MountAuditStatus::Mismatch
+                }
+            }
+        };
+
+        MountAuditEntry {
+            origin: mount.origin,
+            spec: mount.spec,
+            mount_point: mount.mount_point,
+            fs_type: mount.fs_type,
+            options: mount.options,
+            nofail,
+            status,
+            mounted_source: live.map(|l| l.source.clone()),
+            mounted_fs_type: live.map(|l| l.fs_type.clone()),
+            differences,
+        }
+    }
+}
+
+/// Parses fstab lines: spec, mount point, type, options, dump and pass. Swap areas are skipped.
+fn parse_fstab(content: &str) -> Vec<ConfiguredMount> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 3 || fields[2] == "swap" || fields[1] == "none" {
+                return None;
+            }
+            let options: Vec<String> = fields
+                .get(3)
+                .map(|o| o.split(',').map(String::from).collect())
+                .unwrap_or_default();
+
+            Some(ConfiguredMount {
+                origin: FSTAB.to_string(),
+                spec: unescape_mount_path(fields[0]),
+                mount_point: normalize_mount_point(&unescape_mount_path(fields[1])),
+                fs_type: fields[2].to_string(),
+                automatic: !options.iter().any(|o| o == "noauto"),
+                options,
+            })
+        })
+        .collect()
+}
+
+/// Reads the `[Mount]` section of admin-defined `.mount` units. A unit counts as
+/// automatic when some target wants it.
+fn read_mount_units(dir: &Path) -> Vec<ConfiguredMount> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let wanted: HashSet<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_name().to_string_lossy().ends_with(".wants"))
+                .filter_map(|e| fs::read_dir(e.path()).ok())
+                .flat_map(|links| links.flatten().map(|l| l.file_name().to_string_lossy().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut units: Vec<ConfiguredMount> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().ends_with(".mount"))
+        .filter_map(|entry| {
+            let content = fs::read_to_string(entry.path()).ok()?;
+            let mut section = "";
+            let mut values: HashMap<&str, &str> = HashMap::new();
+
+            for line in content.lines().map(str::trim) {
+                if line.starts_with('[') {
+                    section = line;
+                } else if section == "[Mount]" {
+                    if let Some((key, value)) = line.split_once('=') {
+                        values.insert(key.trim(), value.trim());
+                    }
+                }
+            }
+
+            let unit_name = entry.file_name().to_string_lossy().to_string();
+            Some(ConfiguredMount {
+                origin: entry.path().to_string_lossy().to_string(),
+                spec: values.get("What")?.to_string(),
+                mount_point: normalize_mount_point(values.get("Where")?),
+                fs_type: values.get("Type").unwrap_or(&"auto").to_string(),
+                options: values
+                    .get("Options")
+                    .map(|o| o.split(',').map(String::from).collect())
+                    .unwrap_or_default(),
+                automatic: wanted.contains(&unit_name),
+            })
+        })
+        .collect();
+
+    units.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    units
+}
+
+/// Lists configured options the live mount does not carry. `key=value` options match
+/// when the live value starts with the configured one, since the kernel may append
+/// details such as a compression level.
+fn option_differences(configured: &[String], live: &MountEntry) -> Vec<String> {
+    let live_options: Vec<&str> = live
+        .mount_options
+        .iter()
+        .chain(live.super_options.iter())
+        .map(String::as_str)
+        .collect();
+    let mut differences = Vec::new();
+
+    for option in configured {
+        let key = option.split('=').next().unwrap_or(option);
+        if USERSPACE_OPTIONS.contains(&key) || IMPLIED_OPTIONS.contains(&key) || key.starts_with("x-") || key == "comment" {
+            continue;
+        }
+
+        let present = match option.as_str() {
+            "ro" => live.read_only(),
+            "rw" => !live.read_only(),
+            _ => match option.split_once('=') {
+                Some((key, value)) => live_options.iter().any(|o| {
+                    o.split_once('=').is_some_and(|(k, v)| {
+                        k == key && (NORMALIZED_OPTIONS.contains(&key) || v.starts_with(value))
+                    })
+                }),
+                None => live_options.contains(&option.as_str()),
+            },
+        };
+
+        if !present {
+            let detail = match option.as_str() {
+                "rw" => "mounted read-only".to_string(),
+                "ro" => "mounted read-write".to_string(),
+                _ => format!("missing {}", option),
+            };
+            differences.push(detail);
+        }
+    }
+
+    differences
+}
+
+fn normalize_mount_point(path: &str) -> String {
+    let trimmed = path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn live_mount(mount_options: &str, super_options: &str) -> MountEntry {
+        MountEntry {
+            major: 8,
+            minor: 2,
+            mount_point: "/data".to_string(),
+            mount_options: mount_options.split(',').map(String::from).collect(),
+            fs_type: "btrfs".to_string(),
+            source: "/dev/sda2".to_string(),
+            super_options: super_options.split(',').map(String::from).collect(),
+        }
+    }
+
+    fn options(list: &str) -> Vec<String> {
+        list.split(',').map(String::from).collect()
+    }
+
+    #[test]
+    fn ignores_userspace_and_implied_options() {
+        let live = live_mount("rw,relatime", "rw,space_cache");
+        let configured = options("defaults,nofail,x-systemd.automount,exec,suid,rw");
+        assert!(option_differences(&configured, &live).is_empty());
+    }
+
+    #[test]
+    fn reports_missing_and_read_only_options() {
+        let live = live_mount("ro,relatime", "ro,compress=zstd:3,size=10240k");
+        let configured = options("rw,noatime,compress=zstd,size=10m");
+        assert_eq!(
+            option_differences(&configured, &live),
+            vec!["mounted read-only", "missing noatime"]
+        );
+    }
+
+    #[test]
+    fn parses_fstab_entries() {
+        let content = "\
+# <file system> <mount point> <type> <options> <dump> <pass>
+UUID=1234 / ext4 errors=remount-ro 0 1
+/dev/sdb1 /mnt/backup\\040disk/ xfs noauto,nofail 0 2
+/swapfile none swap sw 0 0
+";
+        let mounts = parse_fstab(content);
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(mounts[0].spec, "UUID=1234");
+        assert_eq!(mounts[0].mount_point, "/");
+        assert!(mounts[0].automatic);
+        assert_eq!(mounts[1].mount_point, "/mnt/backup disk");
+        assert!(!mounts[1].automatic);
+    }
+}
@@ -1,15 +1,49 @@
 mod system;
 mod battery;
 mod disk;
+mod diskio;
 mod network;
 mod temperature;
 mod process;
 mod hosts;
+mod dns;
+mod zram;
+mod pattern;
+mod sysfs;
+mod blockdev;
+mod storage;
+mod du;
+mod socket;
+mod ports;
+mod routes;
+mod fstab;
+mod probe;
+mod netstat;
+mod neighbors;
+mod netns;
+mod conntrack;
+mod sensors;
 
 pub use system::SystemInfo;
 pub use battery::BatteryInfo;
-pub use disk::DisksInfo;
-pub use network::NetworkInfo;
+pub use disk::{DisksInfo, DiskFilter, DiskHistory, MountEvent, MountEventKind, MountWatcher};
+pub use blockdev::{BlockDevice, BlockDevicesInfo};
+pub use storage::StorageHealthInfo;
+pub use du::{DuEntry, DuInfo, DuOptions};
+pub use fstab::{MountAuditInfo, MountAuditStatus};
+pub use diskio::{DiskIoInfo, DiskIoSampler};
+pub use network::{InterfaceClass, NetworkFilter, NetworkInfo, NetworkSampler};
+pub use conntrack::{ConntrackCount, ConntrackInfo};
+pub use netstat::{ProtocolCounter, ProtocolStatsInfo, ProtocolStatsSampler, SocketMemory};
+pub use socket::{SocketEntry, SocketFilter, SocketsInfo};
+pub use ports::ListeningPortsInfo;
+pub use probe::{ProbeInfo, ProbeOptions, ProbeResult};
+pub use routes::RoutesInfo;
+pub use neighbors::{NeighborEntry, NeighborsInfo};
+pub use netns::{ContainerRef, NamespaceInterface, NamespaceProcess, NetnsInfo, NetworkNamespace};
 pub use temperature::TemperatureInfo;
-pub use process::ProcessInfo;
+pub use sensors::{SensorChip, SensorReading, SensorsInfo};
+pub use process::{ProcessInfo, ProcessHistory, ProcessSort};
 pub use hosts::HostsInfo;
+pub use dns::DnsInfo;
+pub use zram::ZramInfo;
@@ -0,0 +1,177 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::net::IpAddr;
+
+use super::routes::ip_json;
+
+/// ATF_COM in `/proc/net/arp`: the hardware address is known
+const ATF_COM: u32 = 0x02;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NeighborEntry {
+    /// inet (ARP) or inet6 (NDP)
+    pub family: String,
+    pub address: IpAddr,
+    pub mac_address: Option<String>,
+    pub interface: String,
+    /// NUD state such as REACHABLE, STALE, DELAY, PROBE, FAILED, INCOMPLETE or PERMANENT;
+    /// only COMPLETE or INCOMPLETE when read from /proc/net/arp
+    pub state: String,
+    /// The neighbor advertised itself as an IPv6 router
+    pub is_router: bool,
+}
+
+impl NeighborEntry {
+    pub fn is_failed(&self) -> bool {
+        self.state == "FAILED"
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NeighborsInfo {
+    pub neighbors: Vec<NeighborEntry>,
+    pub error: Option<String>,
+}
+
+impl NeighborsInfo {
+    pub fn collect() -> Self {
+        let mut neighbors: Vec<NeighborEntry> = ip_json(&["neigh", "show"])
+            .iter()
+            .filter_map(parse_ip_neighbor)
+            .collect();
+
+        // `ip` gives nothing both when it is missing and when the table is empty;
+        // /proc/net/arp tells the two apart, but only covers IPv4 and has no NUD states
+        if neighbors.is_empty() {
+            match fs::read_to_string("/proc/net/arp") {
+                Ok(content) => neighbors = parse_proc_arp(&content),
+                Err(e) => {
+                    return Self {
+                        neighbors: vec![],
+                        error: Some(format!("Failed to read /proc/net/arp: {} (Linux only)", e)),
+                    }
+                }
+            }
+        }
+
+        neighbors.sort_by(|a, b| (&a.interface, &a.family, a.address).cmp(&(&b.interface, &b.family, b.address)));
+
+        Self {
+            neighbors,
+            error: None,
+        }
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.neighbors.iter().filter(|n| n.is_failed()).count()
+    }
+}
+
+fn parse_ip_neighbor(neighbor: &Value) -> Option<NeighborEntry> {
+    let address: IpAddr = neighbor.get("dst")?.as_str()?.parse().ok()?;
+    let state = neighbor
+        .get("state")
+        .and_then(|s| s.as_array())
+        .and_then(|states| states.first())
+        .and_then(|s| s.as_str())
+        .unwrap_or("NONE");
+
+    Some(NeighborEntry {
+        family: if address.is_ipv4() { "inet" } else { "inet6" }.to_string(),
+        address,
+        mac_address: neighbor.get("lladdr").and_then(|l| l.as_str()).map(String::from),
+        interface: neighbor.get("dev").and_then(|d| d.as_str()).unwrap_or("-").to_string(),
+        state: state.to_string(),
+        // Flags without a value are printed as `"router": null`
+        is_router: neighbor.get("router").is_some(),
+    })
+}
+
+/// Parses "IP address HW type Flags HW address Mask Device".
+fn parse_proc_arp(content: &str) -> Vec<NeighborEntry> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 {
+                return None;
+            }
+            let flags = u32::from_str_radix(fields[2].trim_start_matches("0x"), 16).ok()?;
+            let complete = flags & ATF_COM != 0;
+
+            Some(NeighborEntry {
+                family: "inet".to_string(),
+                address: fields[0].parse().ok()?,
+                mac_address: complete.then(|| fields[3].to_string()),
+                interface: fields[5].to_string(),
+                state: if complete { "COMPLETE" } else { "INCOMPLETE" }.to_string(),
+                is_router: false,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PROC_ARP: &str = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         aa:bb:cc:dd:ee:01     *        eth0
+192.168.1.77     0x1         0x0         00:00:00:00:00:00     *        eth0
+10.0.0.5         0x1         0x6         aa:bb:cc:dd:ee:05     *        wlan0
+garbage line
+";
+
+    #[test]
+    fn parses_proc_arp() {
+        let entries = parse_proc_arp(PROC_ARP);
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].address, "192.168.1.1".parse::<IpAddr>().unwrap());
+        assert_eq!(entries[0].mac_address.as_deref(), Some("aa:bb:cc:dd:ee:01"));
+        assert_eq!(entries[0].state, "COMPLETE");
+        assert_eq!(entries[0].family, "inet");
+
+        // An unanswered request has no usable hardware address
+        assert_eq!(entries[1].state, "INCOMPLETE");
+        assert!(entries[1].mac_address.is_none());
+
+        // Permanent entries also carry ATF_COM
+        assert_eq!(entries[2].state, "COMPLETE");
+        assert_eq!(entries[2].interface, "wlan0");
+    }
+
+    #[test]
+    fn parses_ip_neighbor_json() {
+        let reachable = json!({
+            "dst": "fe80::1", "dev": "eth0", "lladdr": "aa:bb:cc:dd:ee:01",
+            "router": null, "state": ["REACHABLE"]
+        });
+        let entry = parse_ip_neighbor(&reachable).unwrap();
+        assert_eq!(entry.family, "inet6");
+        assert_eq!(entry.state, "REACHABLE");
+        assert!(entry.is_router);
+        assert_eq!(entry.mac_address.as_deref(), Some("aa:bb:cc:dd:ee:01"));
+
+        let failed = json!({ "dst": "192.168.1.77", "dev": "eth0", "state": ["FAILED"] });
+        let entry = parse_ip_neighbor(&failed).unwrap();
+        assert_eq!(entry.state, "FAILED");
+        assert!(entry.mac_address.is_none());
+        assert!(!entry.is_router);
+
+        let incomplete = json!({ "dst": "192.168.1.78", "dev": "eth0", "state": ["INCOMPLETE"] });
+        assert_eq!(parse_ip_neighbor(&incomplete).unwrap().state, "INCOMPLETE");
+
+        let stateless = json!({ "dst": "192.168.1.79" });
+        let entry = parse_ip_neighbor(&stateless).unwrap();
+        assert_eq!(entry.state, "NONE");
+        assert_eq!(entry.interface, "-");
+
+        assert!(parse_ip_neighbor(&json!({ "dst": "not-an-address" })).is_none());
+        assert!(parse_ip_neighbor(&json!({ "dev": "eth0" })).is_none());
+    }
+}
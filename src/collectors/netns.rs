@@ -0,0 +1,456 @@
+use serde::Serialize;
+#[cfg(target_os = "linux")]
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::CStr,
+    fs::{self, File},
+    net::{Ipv4Addr, Ipv6Addr},
+    os::unix::{fs::MetadataExt, io::AsRawFd},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+#[cfg(target_os = "linux")]
+const NAMED_NETNS_DIR: &str = "/run/netns";
+
+/// Threads inspecting other namespaces at once; each one is left inside the last
+/// namespace it entered, so they are never reused outside this scan
+#[cfg(target_os = "linux")]
+const MAX_WORKERS: usize = 8;
+
+/// IPv4 and IPv6 addresses in CIDR notation, by interface name
+#[cfg(target_os = "linux")]
+type AddressMap = HashMap<String, (Vec<String>, Vec<String>)>;
+
+/// cgroup path prefixes container runtimes put in front of the container id
+#[cfg(target_os = "linux")]
+const RUNTIME_PREFIXES: &[(&str, &str)] = &[
+    ("docker", "docker"),
+    ("cri-containerd", "containerd"),
+    ("crio", "cri-o"),
+    ("libpod", "podman"),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NamespaceInterface {
+    pub name: String,
+    /// Addresses in CIDR notation
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub received: u64,
+    pub transmitted: u64,
+    pub packets_received: u64,
+    pub packets_transmitted: u64,
+    pub errors_received: u64,
+    pub errors_transmitted: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NamespaceProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ContainerRef {
+    /// docker, containerd, cri-o, podman, or kubepods when only the pod slice says so
+    pub runtime: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkNamespace {
+    /// nsfs inode, as in `net:[4026531840]`
+    pub inode: u64,
+    /// Name under /run/netns, for namespaces created with `ip netns add`
+    pub name: Option<String>,
+    /// The namespace weni itself runs in
+    pub is_current: bool,
+    /// Lowest pid in the namespace, usually the container's init process
+    pub leader: Option<NamespaceProcess>,
+    pub process_count: usize,
+    pub containers: Vec<ContainerRef>,
+    pub interfaces: Vec<NamespaceInterface>,
+    /// False when the namespace could not be entered, so IPv4 addresses are unknown
+    pub entered: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetnsInfo {
+    pub namespaces: Vec<NetworkNamespace>,
+    /// Processes whose namespace could not be read; only root can see them all
+    pub hidden_processes: usize,
+    pub error: Option<String>,
+}
+
+impl NetnsInfo {
+    #[cfg(target_os = "linux")]
+    pub fn collect() -> Self {
+        let current = fs::read_link("/proc/self/ns/net")
+            .ok()
+            .and_then(|target| parse_namespace_link(&target.to_string_lossy()));
+        let current = match current {
+            Some(inode) => inode,
+            None => {
+                return Self {
+                    namespaces: vec![],
+                    hidden_processes: 0,
+                    error: Some("Failed to read /proc/self/ns/net (Linux only)".to_string()),
+                }
+            }
+        };
+
+        // Namespace inode to the processes inside it
+        let mut members: BTreeMap<u64, Vec<u32>> = BTreeMap::new();
+        let mut hidden_processes = 0;
+        for pid in list_pids() {
+            match fs::read_link(format!("/proc/{}/ns/net", pid)) {
+                Ok(target) => {
+                    if let Some(inode) = parse_namespace_link(&target.to_string_lossy()) {
+                        members.entry(inode).or_default().push(pid);
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => hidden_processes += 1,
+                // The process exited in the meantime
+                Err(_) => {}
+            }
+        }
+
+        let mut names: HashMap<u64, String> = HashMap::new();
+        if let Ok(entries) = fs::read_dir(NAMED_NETNS_DIR) {
+            for entry in entries.flatten() {
+                // The bind mount's inode is the namespace's own nsfs inode
+                if let Ok(metadata) = fs::metadata(entry.path()) {
+                    names.insert(metadata.ino(), entry.file_name().to_string_lossy().into_owned());
+                    members.entry(metadata.ino()).or_default();
+                }
+            }
+        }
+
+        // The current namespace is read in place; the others are entered with setns, which
+        // moves the calling thread for good, so they go to a small pool of scoped threads
+        let mut namespaces = Vec::with_capacity(members.len());
+        if let Some(pids) = members.remove(&current) {
+            namespaces.push(inspect_namespace(current, names.remove(&current), &pids, true));
+        }
+
+        let others: Vec<(u64, Vec<u32>)> = members.into_iter().collect();
+        let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(4).min(MAX_WORKERS);
+        let next = AtomicUsize::new(0);
+        namespaces.extend(thread::scope(|s| {
+            let handles: Vec<_> = (0..workers.min(others.len()))
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut inspected = Vec::new();
+                        while let Some((inode, pids)) = others.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            inspected.push(inspect_namespace(*inode, names.get(inode).cloned(), pids, false));
+                        }
+                        inspected
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("namespace thread panicked"))
+                .collect::<Vec<_>>()
+        }));
+
+        namespaces.sort_by_key(|ns| (!ns.is_current, ns.name.is_none(), ns.leader.as_ref().map(|p| p.pid)));
+
+        Self {
+            namespaces,
+            hidden_processes,
+            error: None,
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn collect() -> Self {
+        Self {
+            namespaces: vec![],
+            hidden_processes: 0,
+            error: Some("Network namespaces are only available on Linux".to_string()),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn list_pids() -> Vec<u32> {
+    fs::read_dir("/proc")
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.file_name().to_string_lossy().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reads the inode from a `net:[4026531840]` link target.
+#[cfg(target_os = "linux")]
+fn parse_namespace_link(target: &str) -> Option<u64> {
+    target.strip_prefix("net:[")?.strip_suffix(']')?.parse().ok()
+}
+
+#[cfg(target_os = "linux")]
+fn inspect_namespace(inode: u64, name: Option<String>, pids: &[u32], is_current: bool) -> NetworkNamespace {
+    let leader = pids.iter().min().map(|&pid| NamespaceProcess {
+        pid,
+        name: fs::read_to_string(format!("/proc/{}/comm", pid))
+            .map(|c| c.trim().to_string())
+            .unwrap_or_default(),
+    });
+
+    let mut containers: Vec<ContainerRef> = pids
+        .iter()
+        .filter_map(|pid| fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok())
+        .filter_map(|cgroup| container_ref(&cgroup))
+        .collect();
+    containers.sort();
+    containers.dedup();
+
+    let handle = match &name {
+        Some(name) => Some(PathBuf::from(NAMED_NETNS_DIR).join(name)),
+        None => pids.iter().min().map(|pid| PathBuf::from(format!("/proc/{}/ns/net", pid))),
+    };
+
+    let mut namespace = NetworkNamespace {
+        inode,
+        name,
+        is_current,
+        leader,
+        process_count: pids.len(),
+        containers,
+        interfaces: vec![],
+        entered: false,
+        error: None,
+    };
+
+    // Entering needs CAP_SYS_ADMIN; without it /proc/<pid>/net still has counters and IPv6
+    // The current namespace needs no entering, which keeps it fully visible without root
+    let entered = if is_current { Some(Ok(())) } else { handle.as_deref().map(enter_namespace) };
+    match entered {
+        Some(Ok(())) => {
+            namespace.entered = true;
+            namespace.interfaces = read_interfaces(Path::new("/proc/thread-self/net"), Some(interface_addresses()));
+        }
+        Some(Err(e)) => match pids.iter().min() {
+            Some(pid) => {
+                namespace.interfaces = read_interfaces(&PathBuf::from(format!("/proc/{}/net", pid)), None);
+                if namespace.interfaces.is_empty() {
+                    namespace.error = Some(format!("Cannot enter namespace: {}", e));
+                }
+            }
+            None => namespace.error = Some(format!("Cannot enter namespace: {}", e)),
+        },
+        None => namespace.error = Some("No process or name to enter the namespace through".to_string()),
+    }
+
+    namespace
+}
+
+/// Moves the calling thread into the namespace; the caller must be a dedicated thread.
+#[cfg(target_os = "linux")]
+fn enter_namespace(path: &Path) -> std::io::Result<()> {
+    let file = File::open(path)?;
+    // SAFETY: the descriptor stays open for the call, and setns only changes the
+    // namespace of the calling thread, which the caller keeps to itself
+    if unsafe { libc::setns(file.as_raw_fd(), libc::CLONE_NEWNET) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Reads `dev` and `if_inet6` under a `/proc/.../net` directory. IPv4 addresses are only
+/// known when `addresses` comes from inside the namespace.
+#[cfg(target_os = "linux")]
+fn read_interfaces(net_dir: &Path, addresses: Option<AddressMap>) -> Vec<NamespaceInterface> {
+    let Ok(dev) = fs::read_to_string(net_dir.join("dev")) else {
+        return vec![];
+    };
+    let mut addresses = addresses.unwrap_or_else(|| {
+        fs::read_to_string(net_dir.join("if_inet6"))
+            .map(|content| parse_if_inet6(&content))
+            .unwrap_or_default()
+    });
+
+    let mut interfaces: Vec<NamespaceInterface> = dev
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, counters) = line.split_once(':')?;
+            let counters: Vec<u64> = counters.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+            if counters.len() < 11 {
+                return None;
+            }
+            let name = name.trim().to_string();
+            let (ipv4_addresses, ipv6_addresses) = addresses.remove(&name).unwrap_or_default();
+            Some(NamespaceInterface {
+                name,
+                ipv4_addresses,
+                ipv6_addresses,
+                received: counters[0],
+                packets_received: counters[1],
+                errors_received: counters[2],
+                transmitted: counters[8],
+                packets_transmitted: counters[9],
+                errors_transmitted: counters[10],
+            })
+        })
+        .collect();
+
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    interfaces
+}
+
+/// Parses "address ifindex prefix scope flags name", with the address as 32 hex digits.
+#[cfg(target_os = "linux")]
+fn parse_if_inet6(content: &str) -> AddressMap {
+    let mut addresses: AddressMap = HashMap::new();
+    for line in content.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 6 {
+            continue;
+        }
+        let (Ok(address), Ok(prefix)) = (u128::from_str_radix(fields[0], 16), u8::from_str_radix(fields[2], 16)) else {
+            continue;
+        };
+        addresses
+            .entry(fields[5].to_string())
+            .or_default()
+            .1
+            .push(format!("{}/{}", Ipv6Addr::from(address), prefix));
+    }
+    addresses
+}
+
+/// Lists IPv4 and IPv6 addresses per interface in the calling thread's namespace.
+#[cfg(target_os = "linux")]
+fn interface_addresses() -> AddressMap {
+    let mut addresses: AddressMap = HashMap::new();
+    let mut list: *mut libc::ifaddrs = std::ptr::null_mut();
+    // SAFETY: getifaddrs fills `list` with a linked list that stays valid until the
+    // freeifaddrs below; the address and netmask are checked for null and cast by family
+    if unsafe { libc::getifaddrs(&mut list) } != 0 {
+        return addresses;
+    }
+
+    let mut cursor = list;
+    while !cursor.is_null() {
+        let entry = unsafe { &*cursor };
+        cursor = entry.ifa_next;
+        if entry.ifa_addr.is_null() || entry.ifa_netmask.is_null() {
+            continue;
+        }
+        let name = unsafe { CStr::from_ptr(entry.ifa_name) }.to_string_lossy().into_owned();
+
+        match i32::from(unsafe { (*entry.ifa_addr).sa_family }) {
+            libc::AF_INET => {
+                let address = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in) };
+                let netmask = unsafe { &*(entry.ifa_netmask as *const libc::sockaddr_in) };
+                let address = Ipv4Addr::from(u32::from_be(address.sin_addr.s_addr));
+                let prefix = netmask.sin_addr.s_addr.count_ones();
+                addresses.entry(name).or_default().0.push(format!("{}/{}", address, prefix));
+            }
+            libc::AF_INET6 => {
+                let address = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in6) };
+                let netmask = unsafe { &*(entry.ifa_netmask as *const libc::sockaddr_in6) };
+                let address = Ipv6Addr::from(address.sin6_addr.s6_addr);
+                let prefix: u32 = netmask.sin6_addr.s6_addr.iter().map(|b| b.count_ones()).sum();
+                addresses.entry(name).or_default().1.push(format!("{}/{}", address, prefix));
+            }
+            _ => {}
+        }
+    }
+
+    unsafe { libc::freeifaddrs(list) };
+    addresses
+}
+
+/// Finds a container id in `/proc/<pid>/cgroup`, e.g. `/docker/<id>`,
+/// `docker-<id>.scope` or `/kubepods/burstable/pod.../<id>`.
+#[cfg(target_os = "linux")]
+fn container_ref(cgroup: &str) -> Option<ContainerRef> {
+    for line in cgroup.lines() {
+        let Some(path) = line.splitn(3, ':').nth(2) else {
+            continue;
+        };
+        for segment in path.split('/').rev() {
+            let segment = segment.trim_end_matches(".scope");
+            let (prefix, id) = match segment.rsplit_once('-') {
+                Some((prefix, id)) => (Some(prefix), id),
+                None => (None, segment),
+            };
+            if id.len() != 64 || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+                continue;
+            }
+
+            let runtime = prefix
+                .and_then(|p| RUNTIME_PREFIXES.iter().find(|(known, _)| *known == p))
+                .map(|(_, runtime)| *runtime)
+                .or_else(|| {
+                    RUNTIME_PREFIXES
+                        .iter()
+                        .find(|(known, _)| path.contains(&format!("/{}/", known)))
+                        .map(|(_, runtime)| *runtime)
+                })
+                .unwrap_or(if path.contains("kubepods") { "kubepods" } else { "container" });
+            return Some(ContainerRef {
+                runtime: runtime.to_string(),
+                id: id.to_string(),
+            });
+        }
+    }
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    const ID: &str = "3f4e5d6c7b8a99887766554433221100ffeeddccbbaa00112233445566778899";
+
+    #[test]
+    fn finds_container_ids_in_cgroup_paths() {
+        let cases = [
+            (format!("0::/system.slice/docker-{}.scope", ID), "docker"),
+            (format!("12:memory:/docker/{}", ID), "docker"),
+            (format!("0::/system.slice/cri-containerd-{}.scope", ID), "containerd"),
+            (format!("0::/kubepods.slice/kubepods-burstable.slice/crio-{}.scope", ID), "cri-o"),
+            (format!("0::/machine.slice/libpod-{}.scope/container", ID), "podman"),
+            (format!("0::/kubepods/burstable/pod1234/{}", ID), "kubepods"),
+            (format!("0::/custom/{}", ID), "container"),
+        ];
+        for (cgroup, runtime) in cases {
+            let found = container_ref(&cgroup).unwrap_or_else(|| panic!("no container in {}", cgroup));
+            assert_eq!(found.runtime, runtime, "{}", cgroup);
+            assert_eq!(found.id, ID, "{}", cgroup);
+        }
+    }
+
+    #[test]
+    fn ignores_cgroups_without_a_container() {
+        assert!(container_ref("0::/user.slice/user-1000.slice/session-2.scope").is_none());
+        assert!(container_ref("0::/system.slice/docker-3f4e5d6c.scope").is_none());
+        assert!(container_ref("").is_none());
+    }
+
+    #[test]
+    fn parses_if_inet6() {
+        let addresses = parse_if_inet6(
+            "\
+00000000000000000000000000000001 01 80 10 80       lo
+fe800000000000000a0027fffe4e66a1 02 40 20 80     eth0
+20010db8000000000000000000000042 02 40 00 80     eth0
+short line
+zz800000000000000a0027fffe4e66a1 03 40 20 80     eth1
+",
+        );
+        assert_eq!(addresses["lo"].1, vec!["::1/128"]);
+        assert_eq!(addresses["eth0"].1, vec!["fe80::a00:27ff:fe4e:66a1/64", "2001:db8::42/64"]);
+        assert!(addresses["eth0"].0.is_empty());
+        assert!(!addresses.contains_key("eth1"));
+    }
+}
@@ -0,0 +1,314 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::sysfs::read_string;
+
+/// Counters worth watching, as (`/proc/net/snmp` or `/proc/net/netstat` group, field, description).
+/// All of them only ever grow, so any increase points at a problem.
+const COUNTERS: &[(&str, &str, &str)] = &[
+    ("Tcp", "RetransSegs", "TCP segments retransmitted"),
+    ("TcpExt", "TCPTimeouts", "TCP retransmission timeouts"),
+    ("Tcp", "OutRsts", "TCP resets sent"),
+    ("Tcp", "EstabResets", "Established connections reset"),
+    ("Tcp", "AttemptFails", "Failed connection attempts"),
+    ("Tcp", "InErrs", "TCP segments received with errors"),
+    ("TcpExt", "ListenOverflows", "Listen queue overflows"),
+    ("TcpExt", "ListenDrops", "SYNs dropped by listening sockets"),
+    ("TcpExt", "TCPAbortOnMemory", "Connections aborted for lack of memory"),
+    ("Udp", "InErrors", "UDP receive errors"),
+    ("Udp", "RcvbufErrors", "UDP receive buffer overflows"),
+    ("Udp", "SndbufErrors", "UDP send buffer overflows"),
+    ("Udp", "NoPorts", "UDP datagrams to closed ports"),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtocolCounter {
+    /// Group and field name as in `/proc/net`, e.g. `TcpExt.ListenOverflows`
+    pub name: String,
+    pub description: String,
+    /// Value since boot
+    pub total: u64,
+    pub per_sec: f64,
+}
+
+/// Socket counts and memory from `/proc/net/sockstat`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SocketMemory {
+    pub tcp_in_use: u64,
+    pub tcp_orphaned: u64,
+    pub tcp_time_wait: u64,
+    pub tcp_memory_bytes: u64,
+    /// `tcp_mem` thresholds; above pressure the kernel starts shrinking buffers
+    pub tcp_memory_pressure_bytes: Option<u64>,
+    pub tcp_memory_max_bytes: Option<u64>,
+    pub udp_in_use: u64,
+    pub udp_memory_bytes: u64,
+}
+
+impl SocketMemory {
+    pub fn under_pressure(&self) -> bool {
+        self.tcp_memory_pressure_bytes
+            .is_some_and(|pressure| self.tcp_memory_bytes >= pressure)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtocolStatsInfo {
+    pub counters: Vec<ProtocolCounter>,
+    pub sockets: SocketMemory,
+    pub interval_secs: f64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+struct ProtocolSnapshot {
+    taken_at: Instant,
+    /// Keyed by "Group.Field"
+    counters: HashMap<String, u64>,
+}
+
+/// Keeps the previous counter sample so rates can be computed across watch ticks.
+#[derive(Debug, Default)]
+pub struct ProtocolStatsSampler {
+    previous: Option<ProtocolSnapshot>,
+}
+
+impl ProtocolStatsInfo {
+    pub fn collect() -> Self {
+        ProtocolStatsSampler::new().sample()
+    }
+
+    fn unavailable(error: std::io::Error) -> Self {
+        Self {
+            counters: vec![],
+            sockets: SocketMemory::default(),
+            interval_secs: 0.0,
+            error: Some(format!("Failed to read /proc/net/snmp: {} (Linux only)", error)),
+        }
+    }
+}
+
+impl ProtocolStatsSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns rates since the previous call, or over a short sampling window on the first call.
+    pub fn sample(&mut self) -> ProtocolStatsInfo {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => {
+                let first = match read_counters() {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => return ProtocolStatsInfo::unavailable(e),
+                };
+                std::thread::sleep(Duration::from_millis(200));
+                first
+            }
+        };
+
+        let current = match read_counters() {
+            Ok(snapshot) => snapshot,
+            Err(e) => return ProtocolStatsInfo::unavailable(e),
+        };
+
+        let elapsed_secs = current.taken_at.duration_since(previous.taken_at).as_secs_f64();
+        let counters = COUNTERS
+            .iter()
+            .filter_map(|(group, field, description)| {
+                let name = format!("{}.{}", group, field);
+                let now = *current.counters.get(&name)?;
+                let then = previous.counters.get(&name).copied().unwrap_or(now);
+                let per_sec = if elapsed_secs > 0.0 {
+                    now.saturating_sub(then) as f64 / elapsed_secs
+                } else {
+                    0.0
+                };
+                Some(ProtocolCounter {
+                    name,
+                    description: description.to_string(),
+                    total: now,
+                    per_sec,
+                })
+            })
+            .collect();
+
+        self.previous = Some(current);
+
+        ProtocolStatsInfo {
+            counters,
+            sockets: read_sockstat(),
+            interval_secs: elapsed_secs,
+            error: None,
+        }
+    }
+}
+
+fn read_counters() -> Result<ProtocolSnapshot, std::io::Error> {
+    let mut counters = parse_counter_pairs(&fs::read_to_string("/proc/net/snmp")?);
+    // TcpExt is missing on some container runtimes; the basic counters still work
+    if let Ok(content) = fs::read_to_string("/proc/net/netstat") {
+        counters.extend(parse_counter_pairs(&content));
+    }
+    Ok(ProtocolSnapshot {
+        taken_at: Instant::now(),
+        counters,
+    })
+}
+
+/// Parses the header/value line pairs of `/proc/net/snmp` and `/proc/net/netstat`:
+/// "Tcp: RtoAlgorithm RtoMin ..." followed by "Tcp: 1 200 ...".
+fn parse_counter_pairs(content: &str) -> HashMap<String, u64> {
+    let mut counters = HashMap::new();
+    let mut lines = content.lines();
+    while let (Some(header), Some(values)) = (lines.next(), lines.next()) {
+        let (Some((group, names)), Some((_, values))) = (header.split_once(':'), values.split_once(':')) else {
+            continue;
+        };
+        for (name, value) in names.split_whitespace().zip(values.split_whitespace()) {
+            // A few fields such as Tcp.MaxConn are signed; none of them are rates
+            if let Ok(value) = value.parse::<u64>() {
+                counters.insert(format!("{}.{}", group, name), value);
+            }
+        }
+    }
+    counters
+}
+
+fn read_sockstat() -> SocketMemory {
+    let page_size = page_size();
+    let Ok(content) = fs::read_to_string("/proc/net/sockstat") else {
+        return SocketMemory::default();
+    };
+    let mut memory = parse_sockstat(&content, page_size);
+
+    // "min pressure max", in pages
+    if let Some(limits) = read_string(Path::new("/proc/sys/net/ipv4/tcp_mem")) {
+        let limits: Vec<u64> = limits.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+        if limits.len() == 3 {
+            memory.tcp_memory_pressure_bytes = Some(limits[1] * page_size);
+            memory.tcp_memory_max_bytes = Some(limits[2] * page_size);
+        }
+    }
+
+    memory
+}
+
+/// Parses "TCP: inuse 4 orphan 0 tw 1 alloc 4 mem 0"; memory is counted in pages.
+fn parse_sockstat(content: &str, page_size: u64) -> SocketMemory {
+    let mut memory = SocketMemory::default();
+    for line in content.lines() {
+        let Some((protocol, fields)) = line.split_once(':') else {
+            continue;
+        };
+        let fields: Vec<&str> = fields.split_whitespace().collect();
+        let value = |key: &str| {
+            fields
+                .chunks(2)
+                .find(|pair| pair[0] == key)
+                .and_then(|pair| pair.get(1)?.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        match protocol {
+            "TCP" => {
+                memory.tcp_in_use = value("inuse");
+                memory.tcp_orphaned = value("orphan");
+                memory.tcp_time_wait = value("tw");
+                memory.tcp_memory_bytes = value("mem") * page_size;
+            }
+            "UDP" => {
+                memory.udp_in_use = value("inuse");
+                memory.udp_memory_bytes = value("mem") * page_size;
+            }
+            _ => {}
+        }
+    }
+    memory
+}
+
+#[cfg(unix)]
+fn page_size() -> u64 {
+    // SAFETY: sysconf has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 {
+        size as u64
+    } else {
+        4096
+    }
+}
+
+#[cfg(not(unix))]
+fn page_size() -> u64 {
+    4096
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_snmp_headers_with_values() {
+        let counters = parse_counter_pairs(
+            "\
+Ip: Forwarding DefaultTTL InReceives
+Ip: 1 64 123456
+Tcp: RtoAlgorithm RtoMin MaxConn RetransSegs
+Tcp: 1 200 -1 42
+Udp: InDatagrams NoPorts InErrors RcvbufErrors
+Udp: 900 3 7
+",
+        );
+        assert_eq!(counters["Ip.InReceives"], 123456);
+        assert_eq!(counters["Tcp.RetransSegs"], 42);
+        // Signed fields are skipped rather than wrapped
+        assert!(!counters.contains_key("Tcp.MaxConn"));
+        // A value line shorter than its header leaves the extra names out
+        assert_eq!(counters["Udp.InErrors"], 7);
+        assert!(!counters.contains_key("Udp.RcvbufErrors"));
+    }
+
+    #[test]
+    fn ignores_a_header_without_values() {
+        let counters = parse_counter_pairs(
+            "\
+TcpExt: SyncookiesSent ListenDrops
+TcpExt: 0 5
+IpExt: InNoRoutes
+",
+        );
+        assert_eq!(counters["TcpExt.ListenDrops"], 5);
+        assert!(!counters.contains_key("IpExt.InNoRoutes"));
+    }
+
+    #[test]
+    fn parses_sockstat() {
+        let memory = parse_sockstat(
+            "\
+sockets: used 312
+TCP: inuse 12 orphan 1 tw 30 alloc 15 mem 8
+UDP: inuse 4 mem 2
+UDPLITE: inuse 0
+RAW: inuse 0
+FRAG: inuse 0 memory 0
+",
+            4096,
+        );
+        assert_eq!(memory.tcp_in_use, 12);
+        assert_eq!(memory.tcp_orphaned, 1);
+        assert_eq!(memory.tcp_time_wait, 30);
+        assert_eq!(memory.tcp_memory_bytes, 8 * 4096);
+        assert_eq!(memory.udp_in_use, 4);
+        assert_eq!(memory.udp_memory_bytes, 2 * 4096);
+    }
+
+    #[test]
+    fn sockstat_tolerates_missing_values() {
+        let memory = parse_sockstat("TCP: inuse 3 orphan\n", 4096);
+        assert_eq!(memory.tcp_in_use, 3);
+        assert_eq!(memory.tcp_orphaned, 0);
+        assert_eq!(memory.tcp_memory_bytes, 0);
+    }
+}
@@ -1,57 +1,414 @@
 use serde::Serialize;
-use sysinfo::Networks;
+use std::collections::HashMap;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use sysinfo::{NetworkData, Networks};
+
+use super::pattern::matches_any;
+use super::sysfs::read_string;
+
+/// ARPHRD_ETHER and ARPHRD_LOOPBACK from `<linux/if_arp.h>`
+const ARPHRD_ETHER: &str = "1";
+const ARPHRD_LOOPBACK: &str = "772";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterfaceClass {
+    Physical,
+    Loopback,
+    Bridge,
+    Veth,
+    TunTap,
+    Bond,
+    Vlan,
+    Wireless,
+    /// Other software devices: dummy, ifb, vxlan, wireguard, macvlan, ip tunnels, ...
+    Virtual,
+}
+
+impl InterfaceClass {
+    /// Parses a class name as given on the command line.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "physical" | "ethernet" => Some(Self::Physical),
+            "loopback" | "lo" => Some(Self::Loopback),
+            "bridge" => Some(Self::Bridge),
+            "veth" => Some(Self::Veth),
+            "tun" | "tap" | "tuntap" => Some(Self::TunTap),
+            "bond" => Some(Self::Bond),
+            "vlan" => Some(Self::Vlan),
+            "wireless" | "wifi" | "wlan" => Some(Self::Wireless),
+            "virtual" => Some(Self::Virtual),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Physical => "physical",
+            Self::Loopback => "loopback",
+            Self::Bridge => "bridge",
+            Self::Veth => "veth",
+            Self::TunTap => "tun/tap",
+            Self::Bond => "bond",
+            Self::Vlan => "vlan",
+            Self::Wireless => "wireless",
+            Self::Virtual => "virtual",
+        }
+    }
+}
+
+/// Include/exclude rules for interfaces. Unless `show_all` is set or an include rule
+/// is given, loopback and veth interfaces are hidden.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkFilter {
+    pub include_names: Vec<String>,
+    pub exclude_names: Vec<String>,
+    pub include_classes: Vec<InterfaceClass>,
+    pub exclude_classes: Vec<InterfaceClass>,
+    pub show_all: bool,
+}
+
+impl NetworkFilter {
+    pub fn matches(&self, name: &str, class: InterfaceClass) -> bool {
+        if !self.include_names.is_empty() && !matches_any(&self.include_names, name) {
+            return false;
+        }
+        if matches_any(&self.exclude_names, name) {
+            return false;
+        }
+        if !self.include_classes.is_empty() && !self.include_classes.contains(&class) {
+            return false;
+        }
+        if self.exclude_classes.contains(&class) {
+            return false;
+        }
+
+        let explicit = !self.include_names.is_empty() || !self.include_classes.is_empty();
+        self.show_all || explicit || !matches!(class, InterfaceClass::Loopback | InterfaceClass::Veth)
+    }
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct NetworkInterfaceInfo {
     pub name: String,
+    pub class: InterfaceClass,
+    pub mac_address: Option<String>,
+    /// Addresses in CIDR notation, e.g. `192.168.1.10/24`
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub mtu: Option<u32>,
+    /// Operational state: up, down, lower-layer-down, dormant, ...
+    pub oper_state: Option<String>,
+    pub duplex: Option<String>,
+    /// Negotiated link speed in Mbit/s
+    pub speed_mbps: Option<u32>,
+    /// Lifetime counters as reported by the OS, usually since boot
     pub received: u64,
     pub transmitted: u64,
     pub packets_received: u64,
     pub packets_transmitted: u64,
     pub errors_received: u64,
     pub errors_transmitted: u64,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    /// Traffic since the first sample of this run
+    pub session_received: u64,
+    pub session_transmitted: u64,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct NetworkInfo {
     pub interfaces: Vec<NetworkInterfaceInfo>,
+    /// Interfaces left out by the filter
+    pub hidden_count: usize,
+    pub interval_secs: f64,
+}
+
+/// Raw counters for one interface.
+#[derive(Debug, Clone, Copy)]
+struct InterfaceCounters {
+    received: u64,
+    transmitted: u64,
+    packets_received: u64,
+    packets_transmitted: u64,
+    errors_received: u64,
+    errors_transmitted: u64,
+}
+
+/// Addressing and link settings for one interface.
+#[derive(Debug, Clone, Default)]
+struct InterfaceDetails {
+    class: Option<InterfaceClass>,
+    mac_address: Option<String>,
+    ipv4_addresses: Vec<String>,
+    ipv6_addresses: Vec<String>,
+    mtu: Option<u32>,
+    oper_state: Option<String>,
+    duplex: Option<String>,
+    speed_mbps: Option<u32>,
+}
+
+#[derive(Debug)]
+struct NetworkSnapshot {
+    taken_at: Instant,
+    interfaces: HashMap<String, InterfaceCounters>,
+    details: HashMap<String, InterfaceDetails>,
+}
+
+/// Keeps the previous counter sample so rates can be computed across watch ticks,
+/// and the first one so session totals can be reported.
+#[derive(Debug, Default)]
+pub struct NetworkSampler {
+    previous: Option<NetworkSnapshot>,
+    /// Counters at the first sample, per interface
+    baseline: HashMap<String, InterfaceCounters>,
 }
 
 impl NetworkInfo {
-    pub fn collect() -> Self {
-        let networks = Networks::new_with_refreshed_list();
-        let interfaces: Vec<NetworkInterfaceInfo> = networks
-            .iter()
-            .map(|(name, data)| NetworkInterfaceInfo {
-                name: name.to_string(),
-                received: data.total_received(),
-                transmitted: data.total_transmitted(),
-                packets_received: data.total_packets_received(),
-                packets_transmitted: data.total_packets_transmitted(),
-                errors_received: data.total_errors_on_received(),
-                errors_transmitted: data.total_errors_on_transmitted(),
-            })
-            .collect();
+    pub fn collect(filter: &NetworkFilter) -> Self {
+        NetworkSampler::new().sample(filter)
+    }
+}
 
-        Self { interfaces }
+impl NetworkSampler {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn refresh(&mut self) {
-        let mut networks = Networks::new_with_refreshed_list();
-        std::thread::sleep(std::time::Duration::from_millis(100));
-        networks.refresh();
+    /// Returns rates since the previous call, or over a short sampling window on the first call.
+    pub fn sample(&mut self, filter: &NetworkFilter) -> NetworkInfo {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => {
+                let first = read_counters();
+                std::thread::sleep(Duration::from_millis(200));
+                first
+            }
+        };
+        let current = read_counters();
 
-        self.interfaces = networks
+        let elapsed_secs = current.taken_at.duration_since(previous.taken_at).as_secs_f64();
+        let mut interfaces: Vec<NetworkInterfaceInfo> = current
+            .interfaces
             .iter()
-            .map(|(name, data)| NetworkInterfaceInfo {
-                name: name.to_string(),
-                received: data.total_received(),
-                transmitted: data.total_transmitted(),
-                packets_received: data.total_packets_received(),
-                packets_transmitted: data.total_packets_transmitted(),
-                errors_received: data.total_errors_on_received(),
-                errors_transmitted: data.total_errors_on_transmitted(),
+            .map(|(name, now)| {
+                // Interfaces that appear mid-session start their own baseline
+                let then = previous.interfaces.get(name).unwrap_or(now);
+                let start = *self
+                    .baseline
+                    .entry(name.clone())
+                    .or_insert(*previous.interfaces.get(name).unwrap_or(now));
+
+                let details = current.details.get(name).cloned().unwrap_or_default();
+
+                NetworkInterfaceInfo {
+                    name: name.clone(),
+                    class: details.class.unwrap_or_else(|| classify_by_name(name)),
+                    mac_address: details.mac_address,
+                    ipv4_addresses: details.ipv4_addresses,
+                    ipv6_addresses: details.ipv6_addresses,
+                    mtu: details.mtu,
+                    oper_state: details.oper_state,
+                    duplex: details.duplex,
+                    speed_mbps: details.speed_mbps,
+                    received: now.received,
+                    transmitted: now.transmitted,
+                    packets_received: now.packets_received,
+                    packets_transmitted: now.packets_transmitted,
+                    errors_received: now.errors_received,
+                    errors_transmitted: now.errors_transmitted,
+                    rx_bytes_per_sec: per_sec(now.received.saturating_sub(then.received), elapsed_secs),
+                    tx_bytes_per_sec: per_sec(now.transmitted.saturating_sub(then.transmitted), elapsed_secs),
+                    rx_packets_per_sec: per_sec(
+                        now.packets_received.saturating_sub(then.packets_received),
+                        elapsed_secs,
+                    ),
+                    tx_packets_per_sec: per_sec(
+                        now.packets_transmitted.saturating_sub(then.packets_transmitted),
+                        elapsed_secs,
+                    ),
+                    session_received: now.received.saturating_sub(start.received),
+                    session_transmitted: now.transmitted.saturating_sub(start.transmitted),
+                }
             })
             .collect();
+
+        let total = interfaces.len();
+        interfaces.retain(|iface| filter.matches(&iface.name, iface.class));
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+        self.previous = Some(current);
+
+        NetworkInfo {
+            hidden_count: total - interfaces.len(),
+            interfaces,
+            interval_secs: elapsed_secs,
+        }
+    }
+}
+
+fn read_counters() -> NetworkSnapshot {
+    let networks = Networks::new_with_refreshed_list();
+    let details = networks
+        .iter()
+        .map(|(name, data)| (name.to_string(), read_details(name, data)))
+        .collect();
+    let interfaces = networks
+        .iter()
+        .map(|(name, data)| {
+            (
+                name.to_string(),
+                InterfaceCounters {
+                    received: data.total_received(),
+                    transmitted: data.total_transmitted(),
+                    packets_received: data.total_packets_received(),
+                    packets_transmitted: data.total_packets_transmitted(),
+                    errors_received: data.total_errors_on_received(),
+                    errors_transmitted: data.total_errors_on_transmitted(),
+                },
+            )
+        })
+        .collect();
+
+    NetworkSnapshot {
+        taken_at: Instant::now(),
+        interfaces,
+        details,
+    }
+}
+
+/// Addresses come from sysinfo; link settings from `/sys/class/net`, so they are
+/// only filled in on Linux.
+fn read_details(name: &str, data: &NetworkData) -> InterfaceDetails {
+    let mac = data.mac_address();
+    let mut ipv4_addresses = Vec::new();
+    let mut ipv6_addresses = Vec::new();
+    for network in data.ip_networks() {
+        let cidr = format!("{}/{}", network.addr, network.prefix);
+        match network.addr {
+            IpAddr::V4(_) => ipv4_addresses.push(cidr),
+            IpAddr::V6(_) => ipv6_addresses.push(cidr),
+        }
+    }
+
+    let sys = Path::new("/sys/class/net").join(name);
+    InterfaceDetails {
+        class: classify(&sys),
+        mac_address: (!mac.is_unspecified()).then(|| mac.to_string()),
+        ipv4_addresses,
+        ipv6_addresses,
+        mtu: read_string(&sys.join("mtu")).and_then(|v| v.parse().ok()),
+        oper_state: read_string(&sys.join("operstate")).map(|state| match state.as_str() {
+            "lowerlayerdown" => "lower-layer-down".to_string(),
+            "notpresent" => "not-present".to_string(),
+            _ => state,
+        }),
+        // Both read "unknown" or -1, or fail outright, while the link is down or on virtual devices
+        duplex: read_string(&sys.join("duplex")).filter(|d| d != "unknown"),
+        speed_mbps: read_string(&sys.join("speed"))
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|&speed| speed > 0)
+            .and_then(|speed| u32::try_from(speed).ok()),
+    }
+}
+
+fn per_sec(value: u64, elapsed: f64) -> f64 {
+    if elapsed > 0.0 {
+        value as f64 / elapsed
+    } else {
+        0.0
+    }
+}
+
+/// Classifies an interface from its sysfs entry. Returns `None` where sysfs is unavailable.
+fn classify(sys: &Path) -> Option<InterfaceClass> {
+    let uevent = fs::read_to_string(sys.join("uevent")).ok()?;
+    let devtype = uevent.lines().find_map(|line| line.strip_prefix("DEVTYPE="));
+
+    let link_type = read_string(&sys.join("type"));
+    let class = if link_type.as_deref() == Some(ARPHRD_LOOPBACK) {
+        InterfaceClass::Loopback
+    } else if devtype == Some("wlan") || sys.join("wireless").exists() || sys.join("phy80211").exists() {
+        InterfaceClass::Wireless
+    } else if devtype == Some("bridge") || sys.join("bridge").exists() {
+        InterfaceClass::Bridge
+    } else if devtype == Some("bond") || sys.join("bonding").exists() {
+        InterfaceClass::Bond
+    } else if devtype == Some("vlan") {
+        InterfaceClass::Vlan
+    } else if sys.join("tun_flags").exists() {
+        InterfaceClass::TunTap
+    } else if sys.join("device").exists() {
+        InterfaceClass::Physical
+    } else if devtype.is_none()
+        && link_type.as_deref() == Some(ARPHRD_ETHER)
+        && read_string(&sys.join("iflink")) != read_string(&sys.join("ifindex"))
+    {
+        // A software Ethernet device linked to another interface without a DEVTYPE is a
+        // veth peer; ipip, sit and gre tunnels look the same but have other link types
+        InterfaceClass::Veth
+    } else {
+        InterfaceClass::Virtual
+    };
+
+    Some(class)
+}
+
+/// Name-based guess for platforms without sysfs.
+fn classify_by_name(name: &str) -> InterfaceClass {
+    let starts = |prefixes: &[&str]| prefixes.iter().any(|p| name.starts_with(p));
+    if starts(&["lo"]) {
+        InterfaceClass::Loopback
+    } else if starts(&["veth"]) {
+        InterfaceClass::Veth
+    } else if starts(&["br", "bridge", "docker", "virbr"]) {
+        InterfaceClass::Bridge
+    } else if starts(&["tun", "tap", "utun"]) {
+        InterfaceClass::TunTap
+    } else if starts(&["bond"]) {
+        InterfaceClass::Bond
+    } else if starts(&["wl", "wlan", "wifi"]) {
+        InterfaceClass::Wireless
+    } else if name.contains('.') {
+        InterfaceClass::Vlan
+    } else {
+        InterfaceClass::Physical
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Builds a fake `/sys/class/net/<name>` directory from attribute files.
+    fn fake_interface(name: &str, attributes: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("weni-net-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (file, content) in attributes {
+            fs::write(dir.join(file), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn classifies_veth_and_tunnels() {
+        let veth = fake_interface("veth", &[("uevent", "INTERFACE=veth0\nIFINDEX=7\n"), ("type", "1"), ("iflink", "6"), ("ifindex", "7")]);
+        // ipip/sit tunnels report iflink 0 and their own link types
+        let sit = fake_interface("sit", &[("uevent", "INTERFACE=sit0\nIFINDEX=3\n"), ("type", "776"), ("iflink", "0"), ("ifindex", "3")]);
+        let dummy = fake_interface("dummy", &[("uevent", "INTERFACE=dummy0\nIFINDEX=4\n"), ("type", "1"), ("iflink", "4"), ("ifindex", "4")]);
+
+        assert_eq!(classify(&veth), Some(InterfaceClass::Veth));
+        assert_eq!(classify(&sit), Some(InterfaceClass::Virtual));
+        assert_eq!(classify(&dummy), Some(InterfaceClass::Virtual));
+
+        for dir in [veth, sit, dummy] {
+            fs::remove_dir_all(dir).unwrap();
+        }
     }
 }
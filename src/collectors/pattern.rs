@@ -0,0 +1,89 @@
+/// Matches `text` against a shell-style glob supporting `*` and `?`.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` swallow one more character and retry
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Returns true if `text` matches any of the globs.
+pub(crate) fn matches_any(patterns: &[String], text: &str) -> bool {
+    patterns.iter().any(|p| glob_match(p, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_wildcards() {
+        assert!(glob_match("sd?", "sda"));
+        assert!(!glob_match("sd?", "sd"));
+        assert!(!glob_match("sd?", "sda1"));
+        assert!(glob_match("/mnt/*", "/mnt/backup"));
+        assert!(glob_match("*.log", "kern.log"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("eth*0", "eth0"));
+        assert!(!glob_match("eth0", "eth1"));
+    }
+
+    #[test]
+    fn handles_leading_and_trailing_stars() {
+        assert!(glob_match("*loop*", "loop0"));
+        assert!(glob_match("*loop*", "/dev/loop12"));
+        assert!(!glob_match("*loop*", "/dev/sda"));
+        assert!(glob_match("**", "anything"));
+    }
+
+    #[test]
+    fn empty_pattern_matches_only_empty_text() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "sda"));
+    }
+
+    #[test]
+    fn backtracks_past_earlier_matches() {
+        assert!(glob_match("a*b*c", "abc"));
+        assert!(glob_match("a*b*c", "axxbyybzc"));
+        assert!(glob_match("a*b*c", "abcbc"));
+        assert!(!glob_match("a*b*c", "abcb"));
+        assert!(!glob_match("a*b*c", "acb"));
+        assert!(glob_match("*aab", "aaaab"));
+    }
+
+    #[test]
+    fn matches_characters_not_bytes() {
+        assert!(glob_match("?", "é"));
+        assert!(glob_match("/media/*/Fotos", "/media/Jürgen/Fotos"));
+        assert!(glob_match("数据?", "数据盘"));
+        assert!(!glob_match("数据?", "数据"));
+    }
+
+    #[test]
+    fn matches_any_of_several_patterns() {
+        let patterns = vec!["tmpfs".to_string(), "/snap/*".to_string()];
+        assert!(matches_any(&patterns, "/snap/core/1"));
+        assert!(!matches_any(&patterns, "/home"));
+        assert!(!matches_any(&[], "/home"));
+    }
+}
@@ -0,0 +1,78 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use super::process::{ProcessEntry, ProcessInfo};
+use super::socket::{read_sockets, socket_owners};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ListeningPort {
+    /// tcp, tcp6, udp or udp6
+    pub protocol: String,
+    pub address: IpAddr,
+    pub port: u16,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+    pub user: Option<String>,
+    /// Owner of the socket itself, known even when the process is not visible
+    pub uid: u32,
+    /// Bound to 0.0.0.0 or `::`, so reachable on every interface
+    pub all_interfaces: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ListeningPortsInfo {
+    pub ports: Vec<ListeningPort>,
+    pub error: Option<String>,
+}
+
+impl ListeningPortsInfo {
+    /// Lists listening TCP sockets and bound UDP sockets, joined with `processes` for
+    /// names and users. An empty `ports` filter keeps every port.
+    pub fn collect(processes: &ProcessInfo, ports: &[u16]) -> Self {
+        let sockets = match read_sockets() {
+            Ok(sockets) => sockets,
+            Err(e) => {
+                return Self {
+                    ports: vec![],
+                    error: Some(format!("Failed to read /proc/net/tcp: {} (Linux only)", e)),
+                }
+            }
+        };
+
+        let owners = socket_owners();
+        let by_pid: HashMap<u32, &ProcessEntry> = processes.processes.iter().map(|p| (p.pid, p)).collect();
+
+        let mut listening: Vec<ListeningPort> = sockets
+            .into_iter()
+            // Unconnected UDP sockets with a local port are the UDP equivalent of listening
+            .filter(|s| s.state == "LISTEN" || (s.state == "UNCONN" && s.local_port != 0))
+            .filter(|s| ports.is_empty() || ports.contains(&s.local_port))
+            .map(|s| {
+                let owner = owners.get(&s.inode);
+                let pid = owner.map(|(pid, _)| *pid);
+                let process = pid.and_then(|pid| by_pid.get(&pid));
+                ListeningPort {
+                    all_interfaces: s.local_address.is_unspecified(),
+                    protocol: s.protocol,
+                    address: s.local_address,
+                    port: s.local_port,
+                    pid,
+                    process_name: process
+                        .map(|p| p.name.clone())
+                        // Processes that exited between the two scans still have their comm name
+                        .or_else(|| owner.map(|(_, name)| name.clone())),
+                    user: process.and_then(|p| p.user.clone()),
+                    uid: s.uid,
+                }
+            })
+            .collect();
+
+        listening.sort_by(|a, b| (a.port, &a.protocol).cmp(&(b.port, &b.protocol)));
+
+        Self {
+            ports: listening,
+            error: None,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use serde::Serialize;
+use std::fs;
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Targets probed at once; the rest wait in the queue
+const MAX_WORKERS: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct ProbeOptions {
+    /// `host:port` targets given on the command line
+    pub targets: Vec<String>,
+    /// File with more targets, one or more per line; `#` starts a comment
+    pub targets_file: Option<PathBuf>,
+    /// Connect timeout per address
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeResult {
+    /// The target as given
+    pub target: String,
+    pub host: String,
+    pub port: u16,
+    /// Address the connection was made to, or the last one tried
+    pub address: Option<IpAddr>,
+    /// Time spent in name resolution (through the system resolver)
+    pub resolve_ms: Option<f64>,
+    /// Time from connect() to the completed TCP handshake
+    pub connect_ms: Option<f64>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeInfo {
+    pub results: Vec<ProbeResult>,
+    pub failed: usize,
+}
+
+impl ProbeInfo {
+    /// Probes the targets on a bounded pool of threads; results keep the order the targets were given in.
+    pub fn collect(options: &ProbeOptions) -> Result<Self, String> {
+        let mut targets = options.targets.clone();
+        if let Some(ref path) = options.targets_file {
+            let content = fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+            targets.extend(parse_targets_file(&content));
+        }
+        if targets.is_empty() {
+            return Err("No targets given; pass host:port arguments or --targets FILE".to_string());
+        }
+
+        let parsed = targets
+            .iter()
+            .map(|t| split_target(t).ok_or_else(|| format!("Invalid target (expected host:port): {}", t)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        // Workers take the next target from a shared queue and tag results with its index
+        let next = AtomicUsize::new(0);
+        let mut indexed: Vec<(usize, ProbeResult)> = thread::scope(|s| {
+            let handles: Vec<_> = (0..MAX_WORKERS.min(targets.len()))
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut probed = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let (Some(target), Some((host, port))) = (targets.get(i), parsed.get(i)) else {
+                                break;
+                            };
+                            probed.push((i, probe(target, host.clone(), *port, options.timeout)));
+                        }
+                        probed
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("probe thread panicked"))
+                .collect()
+        });
+        indexed.sort_by_key(|(i, _)| *i);
+        let results: Vec<ProbeResult> = indexed.into_iter().map(|(_, result)| result).collect();
+
+        Ok(Self {
+            failed: results.iter().filter(|r| !r.success).count(),
+            results,
+        })
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.failed > 0
+    }
+}
+
+fn parse_targets_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split_whitespace())
+        .map(String::from)
+        .collect()
+}
+
+/// Splits "host:port", "1.2.3.4:port" or "[::1]:port". IPv6 addresses need the
+/// brackets, since "::1:80" is itself a valid address.
+fn split_target(target: &str) -> Option<(String, u16)> {
+    let (host, port) = target.rsplit_once(':')?;
+    let host = match host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        Some(bracketed) => bracketed.parse::<std::net::Ipv6Addr>().ok().map(|_| bracketed)?,
+        None if host.contains(':') => return None,
+        None => host,
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((host.to_string(), port.parse().ok()?))
+}
+
+fn probe(target: &str, host: String, port: u16, timeout: Duration) -> ProbeResult {
+    let mut result = ProbeResult {
+        target: target.to_string(),
+        host,
+        port,
+        address: None,
+        resolve_ms: None,
+        connect_ms: None,
+        success: false,
+        error: None,
+    };
+
+    let started = Instant::now();
+    let addresses: Vec<SocketAddr> = match (result.host.as_str(), port).to_socket_addrs() {
+        Ok(addresses) => addresses.collect(),
+        Err(e) => {
+            result.error = Some(format!("resolve failed: {}", e));
+            return result;
+        }
+    };
+    result.resolve_ms = Some(started.elapsed().as_secs_f64() * 1000.0);
+    if addresses.is_empty() {
+        result.error = Some("resolve failed: no addresses".to_string());
+        return result;
+    }
+
+    // Try each address in resolver order, like a regular client would
+    for address in addresses {
+        result.address = Some(address.ip());
+        let started = Instant::now();
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(_) => {
+                result.connect_ms = Some(started.elapsed().as_secs_f64() * 1000.0);
+                result.success = true;
+                result.error = None;
+                return result;
+            }
+            Err(e) => result.error = Some(e.to_string()),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn options(targets: Vec<String>) -> ProbeOptions {
+        ProbeOptions {
+            targets,
+            targets_file: None,
+            timeout: Duration::from_secs(2),
+        }
+    }
+
+    #[test]
+    fn reaches_a_local_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let target = format!("127.0.0.1:{}", listener.local_addr().unwrap().port());
+
+        let info = ProbeInfo::collect(&options(vec![target.clone()])).unwrap();
+        assert!(!info.has_failures());
+        let result = &info.results[0];
+        assert_eq!(result.target, target);
+        assert!(result.success);
+        assert!(result.connect_ms.is_some());
+        assert_eq!(result.address, Some("127.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn reports_a_closed_port() {
+        // Bind and drop a listener to get a port nothing listens on
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let open = TcpListener::bind("127.0.0.1:0").unwrap();
+        let targets = vec![
+            format!("127.0.0.1:{}", port),
+            format!("127.0.0.1:{}", open.local_addr().unwrap().port()),
+        ];
+
+        let info = ProbeInfo::collect(&options(targets.clone())).unwrap();
+        assert_eq!(info.failed, 1);
+        assert!(info.has_failures());
+        // Results stay in target order
+        assert_eq!(info.results[0].target, targets[0]);
+        assert!(!info.results[0].success);
+        assert!(info.results[0].error.is_some());
+        assert!(info.results[1].success);
+    }
+
+    #[test]
+    fn splits_targets() {
+        assert_eq!(split_target("example.com:443"), Some(("example.com".to_string(), 443)));
+        assert_eq!(split_target("10.0.0.1:22"), Some(("10.0.0.1".to_string(), 22)));
+        assert_eq!(split_target("[::1]:8080"), Some(("::1".to_string(), 8080)));
+        assert_eq!(split_target("[fe80::1]:53"), Some(("fe80::1".to_string(), 53)));
+        // Missing or invalid ports, and IPv6 without brackets
+        assert_eq!(split_target("example.com"), None);
+        assert_eq!(split_target("example.com:"), None);
+        assert_eq!(split_target("example.com:http"), None);
+        assert_eq!(split_target(":80"), None);
+        assert_eq!(split_target("::1:80"), None);
+    }
+
+    #[test]
+    fn parses_targets_file() {
+        let content = "\
+# upstreams
+db.internal:5432   cache.internal:6379
+
+[::1]:22  # local ssh
+   # indented comment
+";
+        assert_eq!(
+            parse_targets_file(content),
+            vec!["db.internal:5432", "cache.internal:6379", "[::1]:22"]
+        );
+        assert!(ProbeInfo::collect(&options(vec!["no-port".to_string()])).is_err());
+        assert!(ProbeInfo::collect(&options(vec![])).is_err());
+    }
+}
@@ -1,24 +1,52 @@
 use serde::Serialize;
-use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind, Users};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ProcessEntry {
     pub pid: u32,
     pub name: String,
+    /// Name of the user owning the process, when it can be resolved
+    pub user: Option<String>,
     pub cpu_usage: f32,
     pub memory_usage: u64,
-    pub disk_read: u64,
-    pub disk_write: u64,
+    /// Bytes read from disk since the process started
+    pub disk_read_total: u64,
+    pub disk_write_total: u64,
+    /// Bytes per second over the sampling window
+    pub disk_read_rate: f64,
+    pub disk_write_rate: f64,
+    /// Resident memory growth in bytes per second over the watch history window
+    pub memory_growth: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+    Memory,
+    Cpu,
+    /// Combined read and write rate
+    Io,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryGrowth {
+    pub pid: u32,
+    pub name: String,
+    pub memory_usage: u64,
+    pub growth_per_sec: f64,
+    pub samples: usize,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ProcessInfo {
     pub processes: Vec<ProcessEntry>,
     pub total_count: usize,
+    pub memory_leaks: Vec<MemoryGrowth>,
 }
 
 impl ProcessInfo {
-    pub fn collect(top_n: Option<usize>, sort_by_cpu: bool) -> Self {
+    pub fn collect(top_n: Option<usize>, sort: ProcessSort) -> Self {
         let mut sys = System::new();
 
         // Refresh processes
@@ -27,11 +55,13 @@ impl ProcessInfo {
             ProcessRefreshKind::new()
                 .with_cpu()
                 .with_memory()
-                .with_disk_usage(),
+                .with_disk_usage()
+                .with_user(UpdateKind::OnlyIfNotSet),
         );
 
-        // Need to refresh twice to get accurate CPU usage
-        std::thread::sleep(std::time::Duration::from_millis(200));
+        // Need to refresh twice to get accurate CPU usage and disk I/O deltas
+        let started = Instant::now();
+        std::thread::sleep(Duration::from_millis(200));
         sys.refresh_processes_specifics(
             ProcessesToUpdate::All,
             ProcessRefreshKind::new()
@@ -40,6 +70,9 @@ impl ProcessInfo {
                 .with_disk_usage(),
         );
 
+        let elapsed = started.elapsed().as_secs_f64();
+        let users = Users::new_with_refreshed_list();
+
         let mut processes: Vec<ProcessEntry> = sys
             .processes()
             .iter()
@@ -48,10 +81,17 @@ impl ProcessInfo {
                 ProcessEntry {
                     pid: pid.as_u32(),
                     name: process.name().to_string_lossy().to_string(),
+                    user: process
+                        .user_id()
+                        .and_then(|uid| users.get_user_by_id(uid))
+                        .map(|user| user.name().to_string()),
                     cpu_usage: process.cpu_usage(),
                     memory_usage: process.memory(),
-                    disk_read: disk_usage.read_bytes,
-                    disk_write: disk_usage.written_bytes,
+                    disk_read_total: disk_usage.total_read_bytes,
+                    disk_write_total: disk_usage.total_written_bytes,
+                    disk_read_rate: disk_usage.read_bytes as f64 / elapsed,
+                    disk_write_rate: disk_usage.written_bytes as f64 / elapsed,
+                    memory_growth: None,
                 }
             })
             .collect();
@@ -59,21 +99,211 @@ impl ProcessInfo {
         let total_count = processes.len();
 
         // Sort processes
-        if sort_by_cpu {
-            processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
-        } else {
-            // Default: sort by memory usage
-            processes.sort_by(|a, b| b.memory_usage.cmp(&a.memory_usage));
+        match sort {
+            ProcessSort::Cpu => {
+                processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
+            }
+            ProcessSort::Memory => {
+                processes.sort_by_key(|p| std::cmp::Reverse(p.memory_usage));
+            }
+            ProcessSort::Io => {
+                processes.sort_by(|a, b| {
+                    let a_rate = a.disk_read_rate + a.disk_write_rate;
+                    let b_rate = b.disk_read_rate + b.disk_write_rate;
+                    b_rate
+                        .partial_cmp(&a_rate)
+                        .unwrap()
+                        .then((b.disk_read_total + b.disk_write_total).cmp(&(a.disk_read_total + a.disk_write_total)))
+                });
+            }
         }
 
+        let mut info = Self {
+            processes,
+            total_count,
+            memory_leaks: Vec::new(),
+        };
+
         // Limit to top N if specified
         if let Some(n) = top_n {
-            processes.truncate(n);
+            info.truncate(n);
         }
 
+        info
+    }
+
+    pub fn truncate(&mut self, n: usize) {
+        self.processes.truncate(n);
+    }
+}
+
+/// Resident memory samples per PID, kept across watch ticks to spot steady growth.
+#[derive(Debug)]
+pub struct ProcessHistory {
+    window: usize,
+    samples: HashMap<u32, ProcessSamples>,
+}
+
+#[derive(Debug)]
+struct ProcessSamples {
+    name: String,
+    memory: VecDeque<(Instant, u64)>,
+}
+
+impl ProcessHistory {
+    pub fn new(window: usize) -> Self {
         Self {
-            processes,
-            total_count,
+            window: window.max(2),
+            samples: HashMap::new(),
         }
     }
+
+    /// Records the current memory of every process and annotates the entries with their
+    /// growth rate. Processes whose memory never shrank over a full window and ended
+    /// higher than it started are reported in `memory_leaks`.
+    pub fn record(&mut self, info: &mut ProcessInfo) {
+        let now = Instant::now();
+        let mut seen = HashSet::with_capacity(info.processes.len());
+        info.memory_leaks.clear();
+
+        for entry in &mut info.processes {
+            seen.insert(entry.pid);
+
+            let samples = self
+                .samples
+                .entry(entry.pid)
+                .or_insert_with(|| ProcessSamples {
+                    name: entry.name.clone(),
+                    memory: VecDeque::with_capacity(self.window),
+                });
+
+            // The PID was reused by a different program; start over
+            if samples.name != entry.name {
+                samples.name = entry.name.clone();
+                samples.memory.clear();
+            }
+
+            samples.memory.push_back((now, entry.memory_usage));
+            while samples.memory.len() > self.window {
+                samples.memory.pop_front();
+            }
+
+            let (first_at, first) = samples.memory[0];
+            let elapsed = now.duration_since(first_at).as_secs_f64();
+            if samples.memory.len() < 2 || elapsed <= 0.0 {
+                continue;
+            }
+
+            let growth_per_sec = (entry.memory_usage as f64 - first as f64) / elapsed;
+            entry.memory_growth = Some(growth_per_sec);
+
+            let steady = samples
+                .memory
+                .iter()
+                .zip(samples.memory.iter().skip(1))
+                .all(|((_, prev), (_, next))| next >= prev);
+
+            if samples.memory.len() == self.window && steady && entry.memory_usage > first {
+                info.memory_leaks.push(MemoryGrowth {
+                    pid: entry.pid,
+                    name: entry.name.clone(),
+                    memory_usage: entry.memory_usage,
+                    growth_per_sec,
+                    samples: samples.memory.len(),
+                });
+            }
+        }
+
+        // Forget processes that have exited
+        self.samples.retain(|pid, _| seen.contains(pid));
+
+        info.memory_leaks
+            .sort_by(|a, b| b.growth_per_sec.partial_cmp(&a.growth_per_sec).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn snapshot(memory: &[(u32, &str, u64)]) -> ProcessInfo {
+        ProcessInfo {
+            processes: memory
+                .iter()
+                .map(|&(pid, name, memory_usage)| ProcessEntry {
+                    pid,
+                    name: name.to_string(),
+                    user: None,
+                    cpu_usage: 0.0,
+                    memory_usage,
+                    disk_read_total: 0,
+                    disk_write_total: 0,
+                    disk_read_rate: 0.0,
+                    disk_write_rate: 0.0,
+                    memory_growth: None,
+                })
+                .collect(),
+            total_count: memory.len(),
+            memory_leaks: Vec::new(),
+        }
+    }
+
+    /// Feeds one tick per value and returns the info from the last tick.
+    fn replay(history: &mut ProcessHistory, name: &str, ticks: &[u64]) -> ProcessInfo {
+        let mut info = snapshot(&[]);
+        for &memory in ticks {
+            // Keep the ticks apart so the elapsed time is never zero
+            thread::sleep(Duration::from_millis(2));
+            info = snapshot(&[(42, name, memory)]);
+            history.record(&mut info);
+        }
+        info
+    }
+
+    #[test]
+    fn flags_steady_growth_over_a_full_window() {
+        let mut history = ProcessHistory::new(4);
+        let info = replay(&mut history, "leaky", &[100, 200, 200, 300]);
+
+        assert_eq!(info.memory_leaks.len(), 1);
+        assert_eq!(info.memory_leaks[0].pid, 42);
+        assert_eq!(info.memory_leaks[0].samples, 4);
+        assert!(info.memory_leaks[0].growth_per_sec > 0.0);
+        assert!(info.processes[0].memory_growth.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn ignores_noisy_and_flat_histories() {
+        let mut history = ProcessHistory::new(4);
+        let info = replay(&mut history, "noisy", &[100, 300, 200, 400]);
+        assert!(info.memory_leaks.is_empty());
+        // The overall trend is still reported, only the leak flag is withheld
+        assert!(info.processes[0].memory_growth.unwrap() > 0.0);
+
+        let mut history = ProcessHistory::new(4);
+        let info = replay(&mut history, "flat", &[100, 100, 100, 100]);
+        assert!(info.memory_leaks.is_empty());
+    }
+
+    #[test]
+    fn waits_for_a_full_window() {
+        let mut history = ProcessHistory::new(4);
+        let info = replay(&mut history, "young", &[100]);
+        assert!(info.processes[0].memory_growth.is_none());
+
+        let info = replay(&mut history, "young", &[200, 300]);
+        assert!(info.memory_leaks.is_empty());
+        assert!(info.processes[0].memory_growth.is_some());
+    }
+
+    #[test]
+    fn restarts_when_the_pid_is_reused() {
+        let mut history = ProcessHistory::new(3);
+        replay(&mut history, "old", &[100, 200]);
+
+        let info = replay(&mut history, "new", &[300]);
+        assert!(info.memory_leaks.is_empty());
+        assert!(info.processes[0].memory_growth.is_none());
+    }
 }
@@ -0,0 +1,335 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::process::Command;
+
+const RTF_UP: u32 = 0x0001;
+const RTF_GATEWAY: u32 = 0x0002;
+const RTF_HOST: u32 = 0x0004;
+const RTF_REINSTATE: u32 = 0x0008;
+const RTF_DYNAMIC: u32 = 0x0010;
+const RTF_MODIFIED: u32 = 0x0020;
+const RTF_REJECT: u32 = 0x0200;
+/// IPv6 only: the route belongs to the local table
+const RTF_LOCAL: u32 = 0x8000_0000;
+
+/// Tables whose rules every system has; rules pointing elsewhere are policy routing
+const STANDARD_TABLES: &[&str] = &["local", "main", "default"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Route {
+    /// inet or inet6
+    pub family: String,
+    /// Destination in CIDR notation
+    pub destination: String,
+    pub gateway: Option<IpAddr>,
+    pub interface: String,
+    pub metric: u32,
+    /// Flags in `route -n` notation, e.g. `UG`
+    pub flags: String,
+    pub table: String,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoutingRule {
+    pub family: String,
+    pub priority: u32,
+    /// Match conditions, e.g. `from 10.0.0.0/8 iif eth1`
+    pub selector: String,
+    pub table: Option<String>,
+}
+
+impl RoutingRule {
+    pub fn is_standard(&self) -> bool {
+        self.selector == "from all"
+            && self.table.as_deref().is_some_and(|t| STANDARD_TABLES.contains(&t))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoutesInfo {
+    pub routes: Vec<Route>,
+    /// Policy routing rules; only available when the `ip` command is installed
+    pub rules: Vec<RoutingRule>,
+    pub error: Option<String>,
+}
+
+impl RoutesInfo {
+    pub fn collect() -> Self {
+        let mut routes = match fs::read_to_string("/proc/net/route") {
+            Ok(content) => parse_ipv4_routes(&content),
+            Err(e) => {
+                return Self {
+                    routes: vec![],
+                    rules: vec![],
+                    error: Some(format!("Failed to read /proc/net/route: {} (Linux only)", e)),
+                }
+            }
+        };
+
+        // /proc/net/route only shows the main table; other tables and the rules need
+        // netlink, which `ip` provides
+        routes.extend(
+            ip_json(&["-4", "route", "show", "table", "all"])
+                .iter()
+                .filter_map(|r| parse_ip_route(r, "inet"))
+                .filter(|r| r.table != "main"),
+        );
+
+        // /proc/net/ipv6_route mixes every table without saying which, so it is only
+        // used when `ip` is missing; then all of its routes are shown as main
+        let ipv6_routes = ip_json(&["-6", "route", "show", "table", "all"]);
+        if !ipv6_routes.is_empty() {
+            routes.extend(ipv6_routes.iter().filter_map(|r| parse_ip_route(r, "inet6")));
+        } else if let Ok(content) = fs::read_to_string("/proc/net/ipv6_route") {
+            routes.extend(parse_ipv6_routes(&content));
+        }
+
+        // Group by family, main table first, keeping the kernel's order within each table
+        routes.sort_by(|a, b| (&a.family, a.table != "main").cmp(&(&b.family, b.table != "main")));
+
+        let mut rules: Vec<RoutingRule> = ip_json(&["rule", "show"])
+            .iter()
+            .filter_map(|r| parse_ip_rule(r, "inet"))
+            .collect();
+        rules.extend(ip_json(&["-6", "rule", "show"]).iter().filter_map(|r| parse_ip_rule(r, "inet6")));
+
+        Self {
+            routes,
+            rules,
+            error: None,
+        }
+    }
+}
+
+/// Parses "Iface Destination Gateway Flags RefCnt Use Metric Mask MTU Window IRTT",
+/// with addresses as little-endian hex.
+fn parse_ipv4_routes(content: &str) -> Vec<Route> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 8 {
+                return None;
+            }
+            let destination = parse_ipv4_hex(fields[1])?;
+            let gateway = parse_ipv4_hex(fields[2])?;
+            let flags = u32::from_str_radix(fields[3], 16).ok()?;
+            let prefix = u32::from_str_radix(fields[7], 16).ok()?.count_ones();
+
+            Some(Route {
+                family: "inet".to_string(),
+                destination: format!("{}/{}", destination, prefix),
+                gateway: (flags & RTF_GATEWAY != 0).then_some(IpAddr::V4(gateway)),
+                interface: fields[0].to_string(),
+                metric: fields[6].parse().ok()?,
+                flags: flag_string(flags),
+                table: "main".to_string(),
+                is_default: prefix == 0,
+            })
+        })
+        .collect()
+}
+
+/// Parses "dest dest_prefix src src_prefix next_hop metric refcnt use flags iface",
+/// with addresses as big-endian hex. The file lists every table without naming it;
+/// local and multicast entries are dropped, and the rest are labelled main.
+fn parse_ipv6_routes(content: &str) -> Vec<Route> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let destination = Ipv6Addr::from(u128::from_str_radix(fields[0], 16).ok()?);
+            let prefix = u8::from_str_radix(fields[1], 16).ok()?;
+            let next_hop = Ipv6Addr::from(u128::from_str_radix(fields[4], 16).ok()?);
+            let metric = u32::from_str_radix(fields[5], 16).ok()?;
+            let flags = u32::from_str_radix(fields[8], 16).ok()?;
+
+            if flags & RTF_LOCAL != 0 || destination.is_multicast() || flags & RTF_REJECT != 0 && fields[9] == "lo" {
+                return None;
+            }
+
+            Some(Route {
+                family: "inet6".to_string(),
+                destination: format!("{}/{}", destination, prefix),
+                gateway: (flags & RTF_GATEWAY != 0).then_some(IpAddr::V6(next_hop)),
+                interface: fields[9].to_string(),
+                metric,
+                flags: flag_string(flags),
+                table: "main".to_string(),
+                is_default: prefix == 0,
+            })
+        })
+        .collect()
+}
+
+fn parse_ipv4_hex(raw: &str) -> Option<Ipv4Addr> {
+    let word = u32::from_str_radix(raw, 16).ok()?;
+    let bytes = word.to_ne_bytes();
+    Some(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]))
+}
+
+fn flag_string(flags: u32) -> String {
+    [
+        (RTF_UP, 'U'),
+        (RTF_GATEWAY, 'G'),
+        (RTF_HOST, 'H'),
+        (RTF_REINSTATE, 'R'),
+        (RTF_DYNAMIC, 'D'),
+        (RTF_MODIFIED, 'M'),
+        (RTF_REJECT, '!'),
+    ]
+    .iter()
+    .filter(|(bit, _)| flags & bit != 0)
+    .map(|(_, c)| *c)
+    .collect()
+}
+
+/// Runs `ip -j <args>` and returns the JSON array, or nothing when `ip` is missing or fails.
+pub(crate) fn ip_json(args: &[&str]) -> Vec<Value> {
+    let output = match Command::new("ip").arg("-j").args(args).output() {
+        Ok(output) if output.status.success() => output.stdout,
+        _ => return vec![],
+    };
+    match serde_json::from_slice(&output) {
+        Ok(Value::Array(items)) => items,
+        _ => vec![],
+    }
+}
+
+/// Converts an `ip -j route` entry. Entries of the local table are skipped, like the
+/// local routes in /proc; `ip` leaves out the table name for the main table.
+fn parse_ip_route(route: &Value, family: &str) -> Option<Route> {
+    let table = route.get("table").and_then(|t| t.as_str()).unwrap_or("main");
+    let dev = route.get("dev").and_then(|d| d.as_str());
+    let reject = matches!(
+        route.get("type").and_then(|t| t.as_str()),
+        Some("unreachable" | "prohibit" | "blackhole")
+    );
+    // The kernel's catch-all unreachable routes on lo, as dropped from /proc/net/ipv6_route
+    if table == "local" || reject && dev == Some("lo") {
+        return None;
+    }
+
+    let dst = route.get("dst")?.as_str()?;
+    let is_default = dst == "default";
+    let gateway: Option<IpAddr> = route.get("gateway").and_then(|g| g.as_str()).and_then(|g| g.parse().ok());
+    let destination = if is_default {
+        if family == "inet" { "0.0.0.0/0" } else { "::/0" }.to_string()
+    } else if dst.contains('/') {
+        dst.to_string()
+    } else {
+        format!("{}/{}", dst, if family == "inet" { 32 } else { 128 })
+    };
+
+    let mut flags = String::from("U");
+    if gateway.is_some() {
+        flags.push('G');
+    }
+    if destination.ends_with(if family == "inet" { "/32" } else { "/128" }) {
+        flags.push('H');
+    }
+    if reject {
+        flags.push('!');
+    }
+
+    Some(Route {
+        family: family.to_string(),
+        destination,
+        gateway,
+        interface: dev.unwrap_or("-").to_string(),
+        metric: route.get("metric").and_then(|m| m.as_u64()).unwrap_or(0) as u32,
+        flags,
+        table: table.to_string(),
+        is_default,
+    })
+}
+
+fn parse_ip_rule(rule: &Value, family: &str) -> Option<RoutingRule> {
+    let text = |key: &str| rule.get(key).and_then(|v| v.as_str());
+
+    let mut selector = Vec::new();
+    if rule.get("not").is_some() {
+        selector.push("not".to_string());
+    }
+    match (text("src"), rule.get("srclen").and_then(|l| l.as_u64())) {
+        (Some(src), Some(len)) => selector.push(format!("from {}/{}", src, len)),
+        (Some(src), None) => selector.push(format!("from {}", src)),
+        _ => {}
+    }
+    match (text("dst"), rule.get("dstlen").and_then(|l| l.as_u64())) {
+        (Some(dst), Some(len)) => selector.push(format!("to {}/{}", dst, len)),
+        (Some(dst), None) => selector.push(format!("to {}", dst)),
+        _ => {}
+    }
+    for (key, label) in [("iif", "iif"), ("oif", "oif"), ("fwmark", "fwmark")] {
+        if let Some(value) = text(key) {
+            selector.push(format!("{} {}", label, value));
+        }
+    }
+
+    Some(RoutingRule {
+        family: family.to_string(),
+        priority: rule.get("priority")?.as_u64()? as u32,
+        selector: selector.join(" "),
+        table: text("table").map(String::from),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_ipv6_route_file() {
+        let content = "\
+fd000000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fd000000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+00000000000000000000000000000001 80 00000000000000000000000000000000 00 00000000000000000000000000000000 00000000 00000002 00000000 80200001       lo
+ff000000000000000000000000000000 08 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+        let routes = parse_ipv6_routes(content);
+        assert_eq!(routes.len(), 2);
+
+        assert_eq!(routes[0].destination, "fd00::/64");
+        assert_eq!(routes[0].gateway, None);
+        assert_eq!(routes[0].metric, 256);
+        assert_eq!(routes[0].flags, "U");
+        assert!(!routes[0].is_default);
+
+        assert_eq!(routes[1].destination, "::/0");
+        assert_eq!(routes[1].gateway, Some("fd00::1".parse().unwrap()));
+        assert_eq!(routes[1].metric, 1024);
+        assert_eq!(routes[1].flags, "UG");
+        assert_eq!(routes[1].table, "main");
+        assert!(routes[1].is_default);
+    }
+
+    #[test]
+    fn labels_ip_routes_with_their_table() {
+        let main = json!({"dst": "default", "gateway": "fd00::1", "dev": "eth0", "metric": 1024});
+        let route = parse_ip_route(&main, "inet6").unwrap();
+        assert_eq!(route.table, "main");
+        assert_eq!(route.destination, "::/0");
+        assert_eq!(route.flags, "UG");
+
+        let policy = json!({"dst": "2001:db8::1", "dev": "eth0", "table": "100", "metric": 1024});
+        let route = parse_ip_route(&policy, "inet6").unwrap();
+        assert_eq!(route.table, "100");
+        assert_eq!(route.destination, "2001:db8::1/128");
+        assert_eq!(route.flags, "UH");
+
+        let local = json!({"type": "local", "dst": "::1", "dev": "lo", "table": "local"});
+        assert!(parse_ip_route(&local, "inet6").is_none());
+        let unreachable = json!({"type": "unreachable", "dst": "default", "dev": "lo", "metric": 4294967295u32});
+        assert!(parse_ip_route(&unreachable, "inet6").is_none());
+    }
+}
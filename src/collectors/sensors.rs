@@ -0,0 +1,323 @@
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::sysfs::read_string;
+
+const HWMON_ROOT: &str = "/sys/class/hwmon";
+
+/// Flags that mark a reading as out of range or broken
+const ALARM_SUFFIXES: &[&str] = &["alarm", "min_alarm", "max_alarm", "crit_alarm", "lcrit_alarm", "fault"];
+
+/// hwmon input kinds: attribute prefix and the divisor from sysfs units
+/// (millidegrees, RPM, millivolts, microwatts, milliamps) to display units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SensorKind {
+    Temperature,
+    Fan,
+    Voltage,
+    Power,
+    Current,
+}
+
+impl SensorKind {
+    const ALL: [SensorKind; 5] = [Self::Temperature, Self::Fan, Self::Voltage, Self::Power, Self::Current];
+
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Temperature => "temp",
+            Self::Fan => "fan",
+            Self::Voltage => "in",
+            Self::Power => "power",
+            Self::Current => "curr",
+        }
+    }
+
+    fn divisor(self) -> f64 {
+        match self {
+            Self::Temperature | Self::Voltage | Self::Current => 1_000.0,
+            Self::Fan => 1.0,
+            Self::Power => 1_000_000.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SensorReading {
+    /// The `*_label` attribute, or the input name when the driver has none
+    pub label: String,
+    /// Attribute prefix such as `temp1` or `in3`
+    pub input: String,
+    /// In °C, RPM, V, W or A depending on the sensor type
+    pub value: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub critical: Option<f64>,
+    /// Set when the driver raises any alarm or fault flag for this input
+    pub alarm: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SensorChip {
+    /// Driver name plus device, like `k10temp-pci-00c3` in lm-sensors
+    pub name: String,
+    pub temperatures: Vec<SensorReading>,
+    pub fans: Vec<SensorReading>,
+    pub voltages: Vec<SensorReading>,
+    pub power: Vec<SensorReading>,
+    pub current: Vec<SensorReading>,
+}
+
+impl SensorChip {
+    pub fn alarm_count(&self) -> usize {
+        [&self.temperatures, &self.fans, &self.voltages, &self.power, &self.current]
+            .iter()
+            .flat_map(|readings| readings.iter())
+            .filter(|r| r.alarm)
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SensorsInfo {
+    pub chips: Vec<SensorChip>,
+    pub error: Option<String>,
+}
+
+impl SensorsInfo {
+    pub fn collect() -> Self {
+        let entries = match fs::read_dir(HWMON_ROOT) {
+            Ok(entries) => entries,
+            // On Linux the class directory only appears once a hwmon driver registers,
+            // so a missing one just means there are no sensors
+            Err(e) if cfg!(target_os = "linux") && e.kind() == std::io::ErrorKind::NotFound => {
+                return Self {
+                    chips: vec![],
+                    error: None,
+                }
+            }
+            Err(e) => {
+                let platform = if cfg!(target_os = "linux") { "" } else { " (Linux only)" };
+                return Self {
+                    chips: vec![],
+                    error: Some(format!("Failed to read {}: {}{}", HWMON_ROOT, e, platform)),
+                };
+            }
+        };
+
+        let mut chips: Vec<SensorChip> = entries
+            .flatten()
+            .filter_map(|entry| read_chip(&entry.path()))
+            .filter(|chip| {
+                !(chip.temperatures.is_empty()
+                    && chip.fans.is_empty()
+                    && chip.voltages.is_empty()
+                    && chip.power.is_empty()
+                    && chip.current.is_empty())
+            })
+            .collect();
+        chips.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self { chips, error: None }
+    }
+
+    pub fn alarm_count(&self) -> usize {
+        self.chips.iter().map(|c| c.alarm_count()).sum()
+    }
+}
+
+fn read_chip(hwmon: &Path) -> Option<SensorChip> {
+    // Kernels before 3.x kept the attributes in the device directory
+    let dir = if hwmon.join("name").exists() {
+        hwmon.to_path_buf()
+    } else {
+        hwmon.join("device")
+    };
+    let driver = read_string(&dir.join("name"))?;
+
+    let name = match fs::canonicalize(hwmon.join("device")) {
+        Ok(device) => format!("{}-{}", driver, device_id(&device)),
+        // Virtual chips such as acpitz have no device
+        Err(_) => format!("{}-virtual", driver),
+    };
+
+    let attributes: Vec<String> = fs::read_dir(&dir)
+        .ok()?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+
+    let mut chip = SensorChip {
+        name,
+        temperatures: vec![],
+        fans: vec![],
+        voltages: vec![],
+        power: vec![],
+        current: vec![],
+    };
+
+    for kind in SensorKind::ALL {
+        let readings = read_readings(&dir, &attributes, kind);
+        match kind {
+            SensorKind::Temperature => chip.temperatures = readings,
+            SensorKind::Fan => chip.fans = readings,
+            SensorKind::Voltage => chip.voltages = readings,
+            SensorKind::Power => chip.power = readings,
+            SensorKind::Current => chip.current = readings,
+        }
+    }
+
+    Some(chip)
+}
+
+/// Shortens the device path the way lm-sensors does: `pci-00c3`, `isa-0000`,
+/// `i2c-1-2d`, or the last path component.
+fn device_id(device: &Path) -> String {
+    let last = device.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let path = device.to_string_lossy();
+
+    if path.contains("/pci") && last.matches(':').count() == 2 {
+        // 0000:00:18.3 -> bus 00, slot 18, function 3
+        let parts: Vec<&str> = last.split([':', '.']).collect();
+        if let [_, bus, slot, function] = parts[..] {
+            let slot = u32::from_str_radix(slot, 16).unwrap_or(0);
+            let function = function.parse::<u32>().unwrap_or(0);
+            return format!("pci-{}{:02x}", bus, (slot << 3) | function);
+        }
+    }
+    if path.contains("/i2c-") {
+        // 1-002d -> bus 1, address 0x2d
+        if let Some((bus, address)) = last.split_once('-') {
+            if let Ok(address) = u16::from_str_radix(address, 16) {
+                return format!("i2c-{}-{:02x}", bus, address);
+            }
+        }
+    }
+    if path.contains("/platform/") {
+        return "isa-0000".to_string();
+    }
+    last
+}
+
+fn read_readings(dir: &Path, attributes: &[String], kind: SensorKind) -> Vec<SensorReading> {
+    let prefix = kind.prefix();
+    let indexes: BTreeSet<u32> = attributes
+        .iter()
+        .filter_map(|name| {
+            let rest = name.strip_prefix(prefix)?;
+            let (index, attribute) = rest.split_once('_')?;
+            // Power meters may only report an average
+            if attribute == "input" || (kind == SensorKind::Power && attribute == "average") {
+                index.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    indexes
+        .into_iter()
+        .filter_map(|index| {
+            let input = format!("{}{}", prefix, index);
+            let attribute = |suffix: &str| -> PathBuf { dir.join(format!("{}_{}", input, suffix)) };
+            let scaled = |suffix: &str| {
+                read_string(&attribute(suffix))?
+                    .parse::<i64>()
+                    .ok()
+                    .map(|raw| raw as f64 / kind.divisor())
+            };
+
+            if read_string(&attribute("enable")).as_deref() == Some("0") {
+                return None;
+            }
+            let value = scaled("input").or_else(|| scaled("average"))?;
+
+            Some(SensorReading {
+                label: read_string(&attribute("label")).unwrap_or_else(|| input.clone()),
+                value,
+                min: scaled("min"),
+                max: scaled("max").or_else(|| if kind == SensorKind::Power { scaled("cap") } else { None }),
+                critical: scaled("crit"),
+                alarm: ALARM_SUFFIXES
+                    .iter()
+                    .any(|suffix| read_string(&attribute(suffix)).is_some_and(|v| v != "0")),
+                input,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortens_device_paths_like_lm_sensors() {
+        let id = |path: &str| device_id(Path::new(path));
+        assert_eq!(id("/sys/devices/pci0000:00/0000:00:18.3"), "pci-00c3");
+        assert_eq!(id("/sys/devices/pci0000:00/0000:00:01.1/0000:01:00.0"), "pci-0100");
+        assert_eq!(id("/sys/devices/pci0000:00/0000:00:1f.3/i2c-1/1-002d"), "i2c-1-2d");
+        assert_eq!(id("/sys/devices/pci0000:00/0000:00:1f.3/i2c-9/9-0000"), "i2c-9-00");
+        assert_eq!(id("/sys/devices/platform/coretemp.0"), "isa-0000");
+        assert_eq!(id("/sys/devices/virtual/thermal/thermal_zone0"), "thermal_zone0");
+    }
+
+    #[test]
+    fn reads_a_fake_hwmon_chip() {
+        let dir = std::env::temp_dir().join(format!("weni-hwmon-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (name, value) in [
+            ("name", "fakechip"),
+            ("temp1_input", "45500"),
+            ("temp1_label", "Tctl"),
+            ("temp1_max", "80000"),
+            ("temp1_crit", "95000"),
+            ("temp1_alarm", "0"),
+            // Disabled channels are skipped even when they have a value
+            ("temp2_input", "30000"),
+            ("temp2_enable", "0"),
+            ("temp3_input", "101000"),
+            ("temp3_crit_alarm", "1"),
+            ("fan1_input", "0"),
+            ("fan1_fault", "1"),
+            // Only an average, with a cap standing in for the maximum
+            ("power1_average", "12500000"),
+            ("power1_cap", "65000000"),
+            ("in0_input", "1200"),
+            ("in0_min_alarm", "0"),
+        ] {
+            fs::write(dir.join(name), format!("{}\n", value)).unwrap();
+        }
+
+        let chip = read_chip(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // Without a device link the chip counts as virtual
+        assert_eq!(chip.name, "fakechip-virtual");
+
+        let inputs: Vec<&str> = chip.temperatures.iter().map(|r| r.input.as_str()).collect();
+        assert_eq!(inputs, vec!["temp1", "temp3"]);
+        let tctl = &chip.temperatures[0];
+        assert_eq!(tctl.label, "Tctl");
+        assert_eq!(tctl.value, 45.5);
+        assert_eq!(tctl.max, Some(80.0));
+        assert_eq!(tctl.critical, Some(95.0));
+        assert!(!tctl.alarm);
+        assert_eq!(chip.temperatures[1].label, "temp3");
+        assert!(chip.temperatures[1].alarm);
+
+        assert!(chip.fans[0].alarm);
+
+        assert_eq!(chip.power.len(), 1);
+        assert_eq!(chip.power[0].value, 12.5);
+        assert_eq!(chip.power[0].max, Some(65.0));
+
+        assert_eq!(chip.voltages[0].value, 1.2);
+        assert!(!chip.voltages[0].alarm);
+        assert!(chip.current.is_empty());
+
+        assert_eq!(chip.alarm_count(), 2);
+    }
+}
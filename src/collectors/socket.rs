@@ -0,0 +1,305 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// `/proc/net` tables and the protocol label each one is shown with
+const SOCKET_TABLES: &[(&str, &str)] = &[
+    ("/proc/net/tcp", "tcp"),
+    ("/proc/net/tcp6", "tcp6"),
+    ("/proc/net/udp", "udp"),
+    ("/proc/net/udp6", "udp6"),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SocketEntry {
+    /// tcp, tcp6, udp or udp6
+    pub protocol: String,
+    pub local_address: IpAddr,
+    pub local_port: u16,
+    pub remote_address: IpAddr,
+    pub remote_port: u16,
+    pub state: String,
+    pub send_queue: u64,
+    pub recv_queue: u64,
+    pub uid: u32,
+    pub inode: u64,
+    /// Owning process, when its file descriptors are readable
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+}
+
+/// State and port filters for the socket table. Empty lists match everything.
+#[derive(Debug, Clone, Default)]
+pub struct SocketFilter {
+    /// State names such as `established` or `CLOSE_WAIT`; case and `-`/`_` are ignored
+    pub states: Vec<String>,
+    /// Matches either the local or the remote port
+    pub ports: Vec<u16>,
+}
+
+impl SocketFilter {
+    fn matches_state(&self, socket: &SocketEntry) -> bool {
+        self.states.is_empty()
+            || self
+                .states
+                .iter()
+                .any(|s| s.to_uppercase().replace('-', "_") == socket.state)
+    }
+
+    fn matches_port(&self, socket: &SocketEntry) -> bool {
+        self.ports.is_empty()
+            || self
+                .ports
+                .iter()
+                .any(|&p| p == socket.local_port || p == socket.remote_port)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SocketsInfo {
+    pub sockets: Vec<SocketEntry>,
+    /// Socket count per state, over every socket matching the port filter
+    pub summary: BTreeMap<String, usize>,
+    pub error: Option<String>,
+}
+
+impl SocketsInfo {
+    pub fn collect(filter: &SocketFilter) -> Self {
+        let mut sockets = match read_sockets() {
+            Ok(sockets) => sockets,
+            Err(e) => {
+                return Self {
+                    sockets: vec![],
+                    summary: BTreeMap::new(),
+                    error: Some(format!("Failed to read /proc/net/tcp: {} (Linux only)", e)),
+                }
+            }
+        };
+
+        sockets.retain(|s| filter.matches_port(s));
+        let mut summary = BTreeMap::new();
+        for socket in &sockets {
+            *summary.entry(socket.state.clone()).or_insert(0) += 1;
+        }
+        sockets.retain(|s| filter.matches_state(s));
+
+        let owners = socket_owners();
+        for socket in &mut sockets {
+            if let Some((pid, name)) = owners.get(&socket.inode) {
+                socket.pid = Some(*pid);
+                socket.process_name = Some(name.clone());
+            }
+        }
+
+        sockets.sort_by(|a, b| {
+            (&a.protocol, &a.state, a.local_port).cmp(&(&b.protocol, &b.state, b.local_port))
+        });
+
+        Self {
+            sockets,
+            summary,
+            error: None,
+        }
+    }
+}
+
+/// Reads every socket table. Fails only when the IPv4 TCP table is missing, since
+/// the IPv6 tables are absent on kernels built without IPv6.
+pub(crate) fn read_sockets() -> Result<Vec<SocketEntry>, std::io::Error> {
+    let mut sockets = Vec::new();
+
+    for (i, (path, protocol)) in SOCKET_TABLES.iter().enumerate() {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if i == 0 => return Err(e),
+            Err(_) => continue,
+        };
+        sockets.extend(content.lines().skip(1).filter_map(|line| parse_socket_line(line, protocol)));
+    }
+
+    Ok(sockets)
+}
+
+/// Parses "sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode".
+fn parse_socket_line(line: &str, protocol: &str) -> Option<SocketEntry> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 10 {
+        return None;
+    }
+
+    let (local_address, local_port) = parse_endpoint(fields[1])?;
+    let (remote_address, remote_port) = parse_endpoint(fields[2])?;
+    let state_code = u8::from_str_radix(fields[3], 16).ok()?;
+    let (tx_queue, rx_queue) = fields[4].split_once(':')?;
+
+    Some(SocketEntry {
+        protocol: protocol.to_string(),
+        local_address,
+        local_port,
+        remote_address,
+        remote_port,
+        state: state_name(state_code, protocol.starts_with("udp")).to_string(),
+        send_queue: u64::from_str_radix(tx_queue, 16).ok()?,
+        recv_queue: u64::from_str_radix(rx_queue, 16).ok()?,
+        uid: fields[7].parse().ok()?,
+        inode: fields[9].parse().ok()?,
+        pid: None,
+        process_name: None,
+    })
+}
+
+/// Decodes "0100007F:1F90". The kernel prints each 32-bit word of the address in
+/// host byte order, so the words are converted back with native endianness.
+fn parse_endpoint(raw: &str) -> Option<(IpAddr, u16)> {
+    let (address, port) = raw.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    if address.len() != 8 && address.len() != 32 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(16);
+    for chunk in address.as_bytes().chunks(8) {
+        let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+        bytes.extend_from_slice(&word.to_ne_bytes());
+    }
+
+    let address = match bytes.len() {
+        4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+        16 => {
+            let octets: [u8; 16] = bytes.try_into().ok()?;
+            let v6 = Ipv6Addr::from(octets);
+            // Dual-stack sockets report IPv4 peers as ::ffff:a.b.c.d
+            match v6.to_ipv4_mapped() {
+                Some(v4) => IpAddr::V4(v4),
+                None => IpAddr::V6(v6),
+            }
+        }
+        _ => return None,
+    };
+
+    Some((address, port))
+}
+
+fn state_name(code: u8, udp: bool) -> &'static str {
+    match code {
+        0x01 => "ESTABLISHED",
+        0x02 => "SYN_SENT",
+        0x03 => "SYN_RECV",
+        0x04 => "FIN_WAIT1",
+        0x05 => "FIN_WAIT2",
+        0x06 => "TIME_WAIT",
+        // UDP sockets that are not connected sit in TCP_CLOSE
+        0x07 if udp => "UNCONN",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE_WAIT",
+        0x09 => "LAST_ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        0x0C => "NEW_SYN_RECV",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Maps socket inodes to the owning process by scanning `/proc/<pid>/fd`. Processes
+/// of other users are only visible to root.
+pub(crate) fn socket_owners() -> HashMap<u64, (u32, String)> {
+    let mut owners = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return owners;
+    };
+
+    for entry in entries.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+
+        let mut name: Option<String> = None;
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            let target = target.to_string_lossy();
+            let Some(inode) = target
+                .strip_prefix("socket:[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|inode| inode.parse::<u64>().ok())
+            else {
+                continue;
+            };
+
+            let name = name.get_or_insert_with(|| {
+                fs::read_to_string(entry.path().join("comm"))
+                    .map(|c| c.trim().to_string())
+                    .unwrap_or_default()
+            });
+            // A socket shared after fork belongs to several processes; keep the lowest pid
+            let owner = owners.entry(inode).or_insert_with(|| (pid, name.clone()));
+            if pid < owner.0 {
+                *owner = (pid, name.clone());
+            }
+        }
+    }
+
+    owners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an address the way the kernel prints it on this host.
+    fn encode(octets: &[u8]) -> String {
+        octets
+            .chunks(4)
+            .map(|word| format!("{:08X}", u32::from_ne_bytes(word.try_into().unwrap())))
+            .collect()
+    }
+
+    #[test]
+    fn decodes_ipv4_endpoints() {
+        let raw = format!("{}:1F90", encode(&[127, 0, 0, 1]));
+        assert_eq!(parse_endpoint(&raw), Some(("127.0.0.1".parse().unwrap(), 8080)));
+    }
+
+    #[test]
+    fn decodes_ipv6_endpoints() {
+        let loopback: Ipv6Addr = "::1".parse().unwrap();
+        let raw = format!("{}:0016", encode(&loopback.octets()));
+        assert_eq!(parse_endpoint(&raw), Some((IpAddr::V6(loopback), 22)));
+
+        let mapped: Ipv6Addr = "::ffff:192.168.1.10".parse().unwrap();
+        let raw = format!("{}:01BB", encode(&mapped.octets()));
+        assert_eq!(parse_endpoint(&raw), Some(("192.168.1.10".parse().unwrap(), 443)));
+
+        assert_eq!(parse_endpoint("0100007F"), None);
+        assert_eq!(parse_endpoint("0100:0016"), None);
+    }
+
+    #[test]
+    fn parses_socket_lines() {
+        let line = format!(
+            "   0: {}:0050 {}:C350 01 0000001A:00000002 00:00000000 00000000  1000        0 123456 1 0000000000000000 20 4 30 10 -1",
+            encode(&[10, 0, 0, 1]),
+            encode(&[10, 0, 0, 2]),
+        );
+        let socket = parse_socket_line(&line, "tcp").unwrap();
+        assert_eq!(socket.local_port, 80);
+        assert_eq!(socket.remote_address, "10.0.0.2".parse::<IpAddr>().unwrap());
+        assert_eq!(socket.remote_port, 50000);
+        assert_eq!(socket.state, "ESTABLISHED");
+        assert_eq!((socket.send_queue, socket.recv_queue), (26, 2));
+        assert_eq!(socket.uid, 1000);
+        assert_eq!(socket.inode, 123456);
+
+        let line = format!(
+            "  12: {}:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 999 2",
+            encode(&[0, 0, 0, 0]),
+        );
+        assert_eq!(parse_socket_line(&line, "udp").unwrap().state, "UNCONN");
+        assert_eq!(parse_socket_line(&line, "tcp").unwrap().state, "CLOSE");
+    }
+}
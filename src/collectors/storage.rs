@@ -0,0 +1,354 @@
+use serde::Serialize;
+use std::fs;
+
+use super::sysfs::{read_string, read_u64};
+
+const SECTOR_SIZE: u64 = 512;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MdMember {
+    pub name: String,
+    pub role: Option<u32>,
+    pub faulty: bool,
+    pub spare: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MdSync {
+    /// resync, recovery, reshape or check
+    pub action: String,
+    pub progress_percent: f32,
+    pub finish: Option<String>,
+    pub speed: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MdArray {
+    pub name: String,
+    pub state: String,
+    pub level: Option<String>,
+    pub size: Option<u64>,
+    pub members: Vec<MdMember>,
+    pub raid_disks: Option<u32>,
+    pub active_disks: Option<u32>,
+    /// Per-slot status as printed by the kernel, e.g. `UU_`
+    pub status: Option<String>,
+    pub degraded: bool,
+    pub sync: Option<MdSync>,
+}
+
+impl MdArray {
+    /// Assembled but not started, usually because too few members were found
+    pub fn is_inactive(&self) -> bool {
+        self.state == "inactive"
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LvmVolume {
+    pub name: String,
+    pub kernel_name: String,
+    pub volume_group: Option<String>,
+    pub logical_volume: Option<String>,
+    pub size: u64,
+    pub devices: Vec<String>,
+    pub suspended: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageHealthInfo {
+    pub md_arrays: Vec<MdArray>,
+    pub lvm_volumes: Vec<LvmVolume>,
+    pub error: Option<String>,
+}
+
+impl StorageHealthInfo {
+    pub fn collect() -> Self {
+        if !cfg!(target_os = "linux") {
+            return Self {
+                md_arrays: vec![],
+                lvm_volumes: vec![],
+                error: Some("RAID and LVM status is only available on Linux".to_string()),
+            };
+        }
+
+        // A missing mdstat only means the md driver is not loaded
+        let md_arrays = fs::read_to_string("/proc/mdstat")
+            .map(|content| Self::parse_mdstat(&content))
+            .unwrap_or_default();
+
+        Self {
+            md_arrays,
+            lvm_volumes: Self::read_lvm_volumes(),
+            error: None,
+        }
+    }
+
+    /// Degraded or inactive arrays
+    pub fn has_failures(&self) -> bool {
+        self.md_arrays.iter().any(|a| a.degraded || a.is_inactive())
+    }
+
+    fn parse_mdstat(content: &str) -> Vec<MdArray> {
+        let mut arrays: Vec<MdArray> = Vec::new();
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with("Personalities") || trimmed.starts_with("unused devices") {
+                continue;
+            }
+
+            // "md0 : active raid1 sdb1[1] sda1[0](F)"
+            if !line.starts_with(char::is_whitespace) {
+                if let Some((name, rest)) = trimmed.split_once(" : ") {
+                    arrays.push(Self::parse_array_header(name, rest));
+                }
+                continue;
+            }
+
+            let Some(array) = arrays.last_mut() else {
+                continue;
+            };
+
+            if trimmed.contains(" blocks") {
+                // "976630464 blocks super 1.2 [2/1] [U_]"
+                array.size = trimmed
+                    .split_whitespace()
+                    .next()
+                    .and_then(|b| b.parse::<u64>().ok())
+                    .map(|blocks| blocks * 1024);
+                for token in trimmed.split_whitespace() {
+                    let Some(inner) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) else {
+                        continue;
+                    };
+                    if let Some((total, active)) = inner.split_once('/') {
+                        array.raid_disks = total.parse().ok();
+                        array.active_disks = active.parse().ok();
+                    } else if !inner.is_empty() && inner.chars().all(|c| c == 'U' || c == '_') {
+                        array.status = Some(inner.to_string());
+                    }
+                }
+            } else if let Some(sync) = Self::parse_sync_line(trimmed) {
+                array.sync = Some(sync);
+            }
+        }
+
+        for array in &mut arrays {
+            let missing = matches!((array.raid_disks, array.active_disks), (Some(t), Some(a)) if a < t);
+            let failed_slot = array.status.as_ref().is_some_and(|s| s.contains('_'));
+            let faulty_member = array.members.iter().any(|m| m.faulty);
+            array.degraded = missing || failed_slot || faulty_member;
+        }
+
+        arrays
+    }
+
+    fn parse_array_header(name: &str, rest: &str) -> MdArray {
+        let mut tokens = rest.split_whitespace().peekable();
+        let state = tokens.next().unwrap_or("unknown").to_string();
+
+        // "(auto-read-only)" and similar qualifiers follow the state
+        while tokens.peek().is_some_and(|t| t.starts_with('(')) {
+            tokens.next();
+        }
+
+        let level = tokens
+            .peek()
+            .filter(|t| !t.contains('['))
+            .map(|t| t.to_string());
+        if level.is_some() {
+            tokens.next();
+        }
+
+        let members = tokens
+            .filter_map(|token| {
+                let (name, rest) = token.split_once('[')?;
+                let (role, flags) = rest.split_once(']')?;
+                Some(MdMember {
+                    name: name.to_string(),
+                    role: role.parse().ok(),
+                    faulty: flags.contains("(F)"),
+                    spare: flags.contains("(S)"),
+                })
+            })
+            .collect();
+
+        MdArray {
+            name: name.trim().to_string(),
+            state,
+            level,
+            size: None,
+            members,
+            raid_disks: None,
+            active_disks: None,
+            status: None,
+            degraded: false,
+            sync: None,
+        }
+    }
+
+    /// Parses "[==>....]  recovery = 12.6% (123/456) finish=100.2min speed=100000K/sec".
+    fn parse_sync_line(line: &str) -> Option<MdSync> {
+        let (before, after) = line.split_once(" = ")?;
+        let action = before.split_whitespace().last()?.to_string();
+        let mut parts = after.split_whitespace();
+        let progress_percent = parts.next()?.trim_end_matches('%').parse().ok()?;
+
+        let mut finish = None;
+        let mut speed = None;
+        for part in parts {
+            if let Some(value) = part.strip_prefix("finish=") {
+                finish = Some(value.to_string());
+            } else if let Some(value) = part.strip_prefix("speed=") {
+                speed = Some(value.to_string());
+            }
+        }
+
+        Some(MdSync {
+            action,
+            progress_percent,
+            finish,
+            speed,
+        })
+    }
+
+    fn read_lvm_volumes() -> Vec<LvmVolume> {
+        let entries = match fs::read_dir("/sys/block") {
+            Ok(entries) => entries,
+            Err(_) => return vec![],
+        };
+
+        let mut volumes: Vec<LvmVolume> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                let uuid = read_string(&path.join("dm/uuid"))?;
+                if !uuid.starts_with("LVM-") {
+                    return None;
+                }
+
+                let name = read_string(&path.join("dm/name"))?;
+                let (volume_group, logical_volume) = split_dm_name(&name);
+                let mut devices: Vec<String> = fs::read_dir(path.join("slaves"))
+                    .map(|slaves| {
+                        slaves
+                            .flatten()
+                            .map(|s| s.file_name().to_string_lossy().to_string())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                devices.sort();
+
+                Some(LvmVolume {
+                    name,
+                    kernel_name: entry.file_name().to_string_lossy().to_string(),
+                    volume_group,
+                    logical_volume,
+                    size: read_u64(&path.join("size")).unwrap_or(0) * SECTOR_SIZE,
+                    devices,
+                    suspended: read_u64(&path.join("dm/suspended")) == Some(1),
+                })
+            })
+            .collect();
+
+        volumes.sort_by(|a, b| a.name.cmp(&b.name));
+        volumes
+    }
+}
+
+/// Splits a device-mapper name such as `vg--data-lv_home` into VG and LV names.
+/// LVM doubles dashes inside either name, so the separator is the first lone dash.
+fn split_dm_name(name: &str) -> (Option<String>, Option<String>) {
+    let chars: Vec<char> = name.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '-' {
+            if chars.get(i + 1) == Some(&'-') {
+                i += 2;
+                continue;
+            }
+            let vg: String = chars[..i].iter().collect();
+            let lv: String = chars[i + 1..].iter().collect();
+            return (Some(vg.replace("--", "-")), Some(lv.replace("--", "-")));
+        }
+        i += 1;
+    }
+
+    (None, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MDSTAT: &str = "\
+Personalities : [raid1] [raid6] [raid5] [raid4]
+md0 : active raid1 sdb1[1] sda1[0]
+      976630464 blocks super 1.2 [2/2] [UU]
+      bitmap: 0/8 pages [0KB], 65536KB chunk
+
+md1 : active raid5 sde1[3] sdd1[1] sdc1[0](F)
+      1953260544 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [_UU]
+      [==>..................]  recovery = 12.6% (123264/976630272) finish=100.2min speed=100000K/sec
+
+md127 : inactive sdf1[1](S)
+      976630464 blocks super 1.2
+
+unused devices: <none>
+";
+
+    #[test]
+    fn parses_mdstat_arrays() {
+        let arrays = StorageHealthInfo::parse_mdstat(MDSTAT);
+        assert_eq!(arrays.len(), 3);
+
+        let md0 = &arrays[0];
+        assert_eq!(md0.name, "md0");
+        assert_eq!(md0.state, "active");
+        assert_eq!(md0.level.as_deref(), Some("raid1"));
+        assert_eq!(md0.size, Some(976630464 * 1024));
+        assert_eq!((md0.raid_disks, md0.active_disks), (Some(2), Some(2)));
+        assert_eq!(md0.status.as_deref(), Some("UU"));
+        assert!(!md0.degraded);
+        assert!(md0.sync.is_none());
+
+        let md1 = &arrays[1];
+        assert!(md1.degraded);
+        assert!(md1.members.iter().any(|m| m.name == "sdc1" && m.faulty && m.role == Some(0)));
+        let sync = md1.sync.as_ref().unwrap();
+        assert_eq!(sync.action, "recovery");
+        assert!((sync.progress_percent - 12.6).abs() < 0.01);
+        assert_eq!(sync.finish.as_deref(), Some("100.2min"));
+        assert_eq!(sync.speed.as_deref(), Some("100000K/sec"));
+    }
+
+    #[test]
+    fn inactive_array_is_a_failure() {
+        let arrays = StorageHealthInfo::parse_mdstat(MDSTAT);
+        let md127 = &arrays[2];
+        assert!(md127.is_inactive());
+        assert!(md127.level.is_none());
+        assert!(md127.members[0].spare);
+
+        let info = StorageHealthInfo {
+            md_arrays: vec![md127.clone()],
+            lvm_volumes: vec![],
+            error: None,
+        };
+        assert!(info.has_failures());
+    }
+
+    #[test]
+    fn splits_dm_names() {
+        assert_eq!(
+            split_dm_name("vg--data-lv_home"),
+            (Some("vg-data".to_string()), Some("lv_home".to_string()))
+        );
+        assert_eq!(
+            split_dm_name("ubuntu--vg-ubuntu--lv"),
+            (Some("ubuntu-vg".to_string()), Some("ubuntu-lv".to_string()))
+        );
+        assert_eq!(split_dm_name("luks--root"), (None, None));
+    }
+}
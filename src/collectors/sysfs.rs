@@ -0,0 +1,21 @@
+use std::fs;
+use std::path::Path;
+
+/// Reads a sysfs/procfs attribute, trimmed. Empty attributes are treated as absent.
+pub(crate) fn read_string(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+pub(crate) fn read_u64(path: &Path) -> Option<u64> {
+    read_string(path)?.parse().ok()
+}
+
+/// Picks the bracketed entry from a choice list such as `none [mq-deadline] kyber`.
+pub(crate) fn active_choice(list: &str) -> Option<String> {
+    list.split_whitespace()
+        .find(|s| s.starts_with('['))
+        .map(|s| s.trim_matches(|c| c == '[' || c == ']').to_string())
+}
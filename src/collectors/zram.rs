@@ -0,0 +1,190 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use super::sysfs::{active_choice, read_string, read_u64};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SwapUsage {
+    pub size: u64,
+    pub used: u64,
+    pub priority: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ZramDevice {
+    pub name: String,
+    pub disk_size: u64,
+    pub algorithm: Option<String>,
+    pub orig_data_size: u64,
+    pub compr_data_size: u64,
+    pub mem_used_total: u64,
+    pub compression_ratio: Option<f64>,
+    /// Swap area backed by this device, if it is in use as swap
+    pub swap: Option<SwapUsage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ZswapInfo {
+    pub enabled: bool,
+    pub compressor: Option<String>,
+    pub zpool: Option<String>,
+    pub max_pool_percent: Option<u32>,
+    pub pool_size: Option<u64>,
+    pub stored_pages: Option<u64>,
+    pub stored_bytes: Option<u64>,
+    pub compression_ratio: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ZramInfo {
+    pub devices: Vec<ZramDevice>,
+    pub zswap: Option<ZswapInfo>,
+    pub error: Option<String>,
+}
+
+impl ZramInfo {
+    pub fn collect() -> Self {
+        if !cfg!(target_os = "linux") {
+            return Self {
+                devices: vec![],
+                zswap: None,
+                error: Some("zram and zswap statistics are only available on Linux".to_string()),
+            };
+        }
+
+        let swaps = Self::read_swaps();
+        let mut devices = Vec::new();
+
+        if let Ok(entries) = fs::read_dir("/sys/block") {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !name.starts_with("zram") {
+                    continue;
+                }
+                if let Some(mut device) = Self::read_device(&entry.path(), &name) {
+                    device.swap = swaps
+                        .iter()
+                        .find(|(path, _)| path == &format!("/dev/{}", name))
+                        .map(|(_, usage)| usage.clone());
+                    devices.push(device);
+                }
+            }
+        }
+
+        devices.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            devices,
+            zswap: Self::read_zswap(),
+            error: None,
+        }
+    }
+
+    fn read_device(path: &Path, name: &str) -> Option<ZramDevice> {
+        let disk_size = read_u64(&path.join("disksize"))?;
+
+        // Fields: orig_data_size compr_data_size mem_used_total mem_limit mem_used_max ...
+        let mm_stat: Vec<u64> = fs::read_to_string(path.join("mm_stat"))
+            .ok()?
+            .split_whitespace()
+            .filter_map(|v| v.parse().ok())
+            .collect();
+        if mm_stat.len() < 3 {
+            return None;
+        }
+
+        // The active algorithm is the one in brackets, e.g. "lzo lz4 [zstd]"
+        let algorithm = read_string(&path.join("comp_algorithm")).and_then(|s| active_choice(&s));
+
+        Some(ZramDevice {
+            name: name.to_string(),
+            disk_size,
+            algorithm,
+            orig_data_size: mm_stat[0],
+            compr_data_size: mm_stat[1],
+            mem_used_total: mm_stat[2],
+            compression_ratio: ratio(mm_stat[0], mm_stat[1]),
+            swap: None,
+        })
+    }
+
+    fn read_swaps() -> Vec<(String, SwapUsage)> {
+        let content = match fs::read_to_string("/proc/swaps") {
+            Ok(c) => c,
+            Err(_) => return vec![],
+        };
+
+        // Filename Type Size Used Priority, sizes in KiB
+        content
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() < 5 {
+                    return None;
+                }
+                Some((
+                    parts[0].to_string(),
+                    SwapUsage {
+                        size: parts[2].parse::<u64>().ok()? * 1024,
+                        used: parts[3].parse::<u64>().ok()? * 1024,
+                        priority: parts[4].parse().ok()?,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    fn read_zswap() -> Option<ZswapInfo> {
+        let params = Path::new("/sys/module/zswap/parameters");
+        let enabled = fs::read_to_string(params.join("enabled")).ok()?;
+
+        // The debugfs counters need root; newer kernels also report the pool in meminfo
+        let debug = Path::new("/sys/kernel/debug/zswap");
+        let (meminfo_pool, meminfo_stored) = Self::read_meminfo_zswap();
+        let pool_size = read_u64(&debug.join("pool_total_size")).or(meminfo_pool);
+        let stored_pages = read_u64(&debug.join("stored_pages"));
+
+        Some(ZswapInfo {
+            enabled: enabled.trim() == "Y",
+            compressor: read_string(&params.join("compressor")),
+            zpool: read_string(&params.join("zpool")),
+            max_pool_percent: read_string(&params.join("max_pool_percent"))
+                .and_then(|v| v.parse().ok()),
+            pool_size,
+            stored_pages,
+            stored_bytes: meminfo_stored,
+            compression_ratio: match (meminfo_stored, pool_size) {
+                (Some(stored), Some(pool)) => ratio(stored, pool),
+                _ => None,
+            },
+        })
+    }
+
+    fn read_meminfo_zswap() -> (Option<u64>, Option<u64>) {
+        let content = match fs::read_to_string("/proc/meminfo") {
+            Ok(c) => c,
+            Err(_) => return (None, None),
+        };
+
+        let field = |key: &str| {
+            content
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .and_then(|rest| rest.split_whitespace().next())
+                .and_then(|kb| kb.parse::<u64>().ok())
+                .map(|kb| kb * 1024)
+        };
+
+        (field("Zswap:"), field("Zswapped:"))
+    }
+}
+
+fn ratio(original: u64, compressed: u64) -> Option<f64> {
+    if compressed > 0 {
+        Some(original as f64 / compressed as f64)
+    } else {
+        None
+    }
+}
@@ -2,8 +2,15 @@ use anyhow::Result;
 use colored::Colorize;
 use comfy_table::{Table, Row, Cell, presets::UTF8_FULL};
 use serde::Serialize;
+use std::io::{self, Write};
 
-use crate::collectors::{SystemInfo, BatteryInfo, DisksInfo, NetworkInfo, TemperatureInfo, ProcessInfo, HostsInfo};
+use crate::collectors::{SystemInfo, BatteryInfo, DisksInfo, DiskIoInfo, BlockDevice, BlockDevicesInfo, StorageHealthInfo, MountAuditInfo, MountAuditStatus, DuEntry, DuInfo, ProbeInfo, MountEventKind, NetworkInfo, SocketEntry, SocketsInfo, ListeningPortsInfo, ProtocolStatsInfo, ConntrackInfo, ConntrackCount, RoutesInfo, NeighborsInfo, NetnsInfo, TemperatureInfo, SensorsInfo, ProcessInfo, HostsInfo, DnsInfo, ZramInfo};
+
+/// Byte or inode usage at which a filesystem is reported as nearly full
+const DISK_FULL_PERCENT: f32 = 90.0;
+
+/// Device utilization at which a disk is considered saturated
+const DISK_SATURATED_PERCENT: f64 = 90.0;
 
 pub enum OutputFormat {
     Text,
@@ -11,41 +18,191 @@ pub enum OutputFormat {
 }
 
 #[derive(Serialize)]
-struct AllInfo {
-    system: SystemInfo,
-    battery: Option<BatteryInfo>,
-    disks: Option<DisksInfo>,
-    network: Option<NetworkInfo>,
-    temperature: Option<TemperatureInfo>,
-    processes: Option<ProcessInfo>,
-    hosts: Option<HostsInfo>,
+pub struct AllInfo {
+    pub system: SystemInfo,
+    pub battery: Option<BatteryInfo>,
+    pub disks: Option<DisksInfo>,
+    pub disk_io: Option<DiskIoInfo>,
+    pub block_devices: Option<BlockDevicesInfo>,
+    pub storage: Option<StorageHealthInfo>,
+    pub mount_audit: Option<MountAuditInfo>,
+    pub network: Option<NetworkInfo>,
+    pub routes: Option<RoutesInfo>,
+    pub neighbors: Option<NeighborsInfo>,
+    pub namespaces: Option<NetnsInfo>,
+    pub sockets: Option<SocketsInfo>,
+    pub listening_ports: Option<ListeningPortsInfo>,
+    pub protocol_stats: Option<ProtocolStatsInfo>,
+    pub conntrack: Option<ConntrackInfo>,
+    pub temperature: Option<TemperatureInfo>,
+    pub sensors: Option<SensorsInfo>,
+    pub processes: Option<ProcessInfo>,
+    pub hosts: Option<HostsInfo>,
+    pub dns: Option<DnsInfo>,
+    pub zram: Option<ZramInfo>,
+}
+
+impl AllInfo {
+    /// True when a collected section reports a condition that `--check` should fail on.
+    pub fn has_failures(&self) -> bool {
+        self.storage.as_ref().is_some_and(|s| s.has_failures())
+            || self.mount_audit.as_ref().is_some_and(|m| m.has_failed_mounts())
+            || self.conntrack.as_ref().is_some_and(|c| c.is_over_threshold())
+    }
 }
 
-pub fn display_info(
-    system_info: SystemInfo,
-    battery_info: Option<BatteryInfo>,
-    disks_info: Option<DisksInfo>,
-    network_info: Option<NetworkInfo>,
-    temp_info: Option<TemperatureInfo>,
-    process_info: Option<ProcessInfo>,
-    hosts_info: Option<HostsInfo>,
-    format: OutputFormat,
-) -> Result<()> {
+pub fn display_info(info: &AllInfo, format: OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Text => display_text(&system_info, &battery_info, &disks_info, &network_info, &temp_info, &process_info, &hosts_info),
-        OutputFormat::Json => display_json(&system_info, &battery_info, &disks_info, &network_info, &temp_info, &process_info, &hosts_info),
+        OutputFormat::Text => display_text(info),
+        OutputFormat::Json => display_json(info),
+    }
+}
+
+/// A closed pipe (e.g. `weni du / --tree | head`) ends the output quietly.
+pub fn display_du(info: &DuInfo, tree: bool, format: OutputFormat) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    let written = match format {
+        OutputFormat::Json => writeln!(stdout, "{}", serde_json::to_string_pretty(info)?),
+        OutputFormat::Text => write_du_text(&mut stdout, info, tree),
+    };
+    match written.and_then(|_| stdout.flush()) {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+fn write_du_text(out: &mut impl Write, info: &DuInfo, tree: bool) -> io::Result<()> {
+    let root = &info.root;
+    writeln!(out, "\n{}", format!("Disk Usage: {}", root.path).bold().cyan())?;
+
+    let mut du_table = Table::new();
+    du_table.load_preset(UTF8_FULL);
+    du_table.set_header(vec![
+        Cell::new("Size").fg(comfy_table::Color::Yellow),
+        Cell::new("%").fg(comfy_table::Color::Yellow),
+        Cell::new("Files").fg(comfy_table::Color::Yellow),
+        Cell::new("Path").fg(comfy_table::Color::Yellow),
+    ]);
+
+    if tree {
+        add_du_tree_rows(&mut du_table, root, root.size, "", None);
+    } else {
+        // Flatten every listed level into one table, largest first
+        let mut entries = Vec::new();
+        collect_du_entries(root, &mut entries);
+        entries.sort_by_key(|e| std::cmp::Reverse(e.size));
+        for entry in entries {
+            let path = if entry.is_dir {
+                format!("{}/", entry.path.trim_end_matches('/'))
+            } else {
+                entry.path.clone()
+            };
+            du_table.add_row(vec![
+                Cell::new(format_bytes(entry.size)),
+                Cell::new(format_share(entry.size, root.size)),
+                Cell::new(entry.file_count.to_string()),
+                Cell::new(path),
+            ]);
+        }
+    }
+
+    writeln!(out, "{du_table}")?;
+    writeln!(
+        out,
+        "\nTotal: {} in {} files",
+        format_bytes(root.size),
+        root.file_count
+    )?;
+    if info.errors > 0 {
+        writeln!(out, "{}", format!("{} entries could not be read (permission denied?)", info.errors).yellow())?;
+    }
+    writeln!(out)
+}
+
+pub fn display_probe(info: &ProbeInfo, format: OutputFormat) -> Result<()> {
+    if let OutputFormat::Json = format {
+        println!("{}", serde_json::to_string_pretty(info)?);
+        return Ok(());
+    }
+
+    println!("\n{}", "Connectivity Probe".bold().cyan());
+
+    let mut probe_table = Table::new();
+    probe_table.load_preset(UTF8_FULL);
+    probe_table.set_header(vec![
+        Cell::new("Target").fg(comfy_table::Color::Yellow),
+        Cell::new("Address").fg(comfy_table::Color::Yellow),
+        Cell::new("Resolve").fg(comfy_table::Color::Yellow),
+        Cell::new("Connect").fg(comfy_table::Color::Yellow),
+        Cell::new("Status").fg(comfy_table::Color::Yellow),
+    ]);
+
+    for result in &info.results {
+        let format_ms = |ms: Option<f64>| ms.map(|ms| format!("{:.1} ms", ms)).unwrap_or_else(|| "-".to_string());
+        let status = if result.success {
+            Cell::new("OK").fg(comfy_table::Color::Green)
+        } else {
+            Cell::new(result.error.as_deref().unwrap_or("failed")).fg(comfy_table::Color::Red)
+        };
+        probe_table.add_row(vec![
+            Cell::new(&result.target),
+            Cell::new(result.address.map(|a| a.to_string()).unwrap_or_else(|| "-".to_string())),
+            Cell::new(format_ms(result.resolve_ms)),
+            Cell::new(format_ms(result.connect_ms)),
+            status,
+        ]);
+    }
+
+    println!("{probe_table}");
+    let reachable = info.results.len() - info.failed;
+    let summary = format!("\n{} of {} targets reachable", reachable, info.results.len());
+    if info.has_failures() {
+        println!("{}", summary.red());
+    } else {
+        println!("{}", summary.green());
+    }
+    println!();
+    Ok(())
+}
+
+fn collect_du_entries<'a>(entry: &'a DuEntry, out: &mut Vec<&'a DuEntry>) {
+    for child in &entry.children {
+        out.push(child);
+        collect_du_entries(child, out);
+    }
+}
+
+fn add_du_tree_rows(table: &mut Table, entry: &DuEntry, total: u64, indent: &str, is_last: Option<bool>) {
+    let (branch, child_indent) = match is_last {
+        None => ("", String::new()),
+        Some(true) => ("└─", format!("{}  ", indent)),
+        Some(false) => ("├─", format!("{}│ ", indent)),
+    };
+    let name = if is_last.is_none() { &entry.path } else { &entry.name };
+    let suffix = if entry.is_dir && is_last.is_some() { "/" } else { "" };
+
+    table.add_row(vec![
+        Cell::new(format_bytes(entry.size)),
+        Cell::new(format_share(entry.size, total)),
+        Cell::new(entry.file_count.to_string()),
+        Cell::new(format!("{}{}{}{}", indent, branch, name, suffix)),
+    ]);
+
+    for (i, child) in entry.children.iter().enumerate() {
+        add_du_tree_rows(table, child, total, &child_indent, Some(i + 1 == entry.children.len()));
+    }
+}
+
+fn format_share(part: u64, total: u64) -> String {
+    if total > 0 {
+        format!("{:.1}%", part as f64 / total as f64 * 100.0)
+    } else {
+        "-".to_string()
     }
 }
 
-fn display_text(
-    system_info: &SystemInfo,
-    battery_info: &Option<BatteryInfo>,
-    disks_info: &Option<DisksInfo>,
-    network_info: &Option<NetworkInfo>,
-    temp_info: &Option<TemperatureInfo>,
-    process_info: &Option<ProcessInfo>,
-    hosts_info: &Option<HostsInfo>,
-) -> Result<()> {
+fn display_text(info: &AllInfo) -> Result<()> {
+    let system_info = &info.system;
 
     if let Some(os) = &system_info.os {
         println!("\n{}", "System Information".bold().cyan());
@@ -124,7 +281,7 @@ fn display_text(
         println!("{mem_table}");
     }
 
-    if let Some(battery) = battery_info {
+    if let Some(battery) = &info.battery {
         println!("\n{}", "Battery Information".bold().cyan());
 
         if let Some(ref error) = battery.error {
@@ -170,8 +327,37 @@ fn display_text(
         }
     }
 
-    if let Some(disks) = disks_info {
+    if let Some(disks) = &info.disks {
         println!("\n{}", "Disk Information".bold().cyan());
+
+        if !disks.event_log.is_empty() {
+            println!("{}", "Mount Events".bold());
+            for event in &disks.event_log {
+                let kind = match event.kind {
+                    MountEventKind::Mounted => "mounted".green(),
+                    MountEventKind::Unmounted => "unmounted".red(),
+                    MountEventKind::Remounted => "remounted".yellow(),
+                };
+                let mut line = format!(
+                    "  {} {} {} ({}, {}",
+                    format_clock(event.timestamp),
+                    kind,
+                    event.mount_point,
+                    event.device,
+                    event.file_system
+                );
+                if event.removable {
+                    line.push_str(", removable");
+                }
+                line.push(')');
+                if let Some(ref previous) = event.previous_options {
+                    line.push_str(&format!(" {} -> {}", previous.join(","), event.options.join(",")));
+                }
+                println!("{}", line);
+            }
+            println!();
+        }
+
         for disk in &disks.disks {
             let mut disk_table = Table::new();
             disk_table.load_preset(UTF8_FULL);
@@ -183,10 +369,35 @@ fn display_text(
                 Cell::new("Name").fg(comfy_table::Color::Yellow),
                 Cell::new(&disk.name),
             ]));
+            if let Some(ref source) = disk.source {
+                if source != &disk.name {
+                    disk_table.add_row(Row::from(vec![
+                        Cell::new("Source").fg(comfy_table::Color::Yellow),
+                        Cell::new(source),
+                    ]));
+                }
+            }
+            if let Some(ref device_id) = disk.device_id {
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Device ID").fg(comfy_table::Color::Yellow),
+                    Cell::new(device_id),
+                ]));
+            }
             disk_table.add_row(Row::from(vec![
                 Cell::new("File System").fg(comfy_table::Color::Yellow),
                 Cell::new(&disk.file_system),
             ]));
+            if !disk.mount_options.is_empty() {
+                let options = Cell::new(disk.mount_options.join(","));
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Options").fg(comfy_table::Color::Yellow),
+                    if disk.unexpectedly_read_only() {
+                        options.fg(comfy_table::Color::Red)
+                    } else {
+                        options
+                    },
+                ]));
+            }
             disk_table.add_row(Row::from(vec![
                 Cell::new("Total").fg(comfy_table::Color::Yellow),
                 Cell::new(format_bytes(disk.total_space)),
@@ -201,24 +412,411 @@ fn display_text(
             ]));
             disk_table.add_row(Row::from(vec![
                 Cell::new("Usage").fg(comfy_table::Color::Yellow),
-                Cell::new(format!("{:.2}%", disk.usage_percent)),
+                usage_cell(disk.usage_percent),
             ]));
+            if let (Some(total), Some(used), Some(free), Some(percent)) = (
+                disk.total_inodes,
+                disk.used_inodes,
+                disk.free_inodes,
+                disk.inode_usage_percent,
+            ) {
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Inodes").fg(comfy_table::Color::Yellow),
+                    Cell::new(format!("{} used / {} free / {} total", used, free, total)),
+                ]));
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Inode Usage").fg(comfy_table::Color::Yellow),
+                    usage_cell(percent),
+                ]));
+            }
+            if let Some(rate) = disk.fill_rate {
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Fill Rate").fg(comfy_table::Color::Yellow),
+                    Cell::new(format!("{}/h", format_signed_bytes(rate * 3600.0))),
+                ]));
+            }
+            if let Some(secs) = disk.time_to_full_secs {
+                let cell = Cell::new(format_eta(secs));
+                disk_table.add_row(Row::from(vec![
+                    Cell::new("Full In").fg(comfy_table::Color::Yellow),
+                    if disk.full_soon { cell.fg(comfy_table::Color::Red) } else { cell },
+                ]));
+            }
             disk_table.add_row(Row::from(vec![
                 Cell::new("Removable").fg(comfy_table::Color::Yellow),
                 Cell::new(if disk.is_removable { "Yes" } else { "No" }),
             ]));
-            println!("{disk_table}\n");
+            println!("{disk_table}");
+
+            if disk.unexpectedly_read_only() {
+                println!(
+                    "{}",
+                    format!(
+                        "Warning: {} is mounted read-only; check the kernel log for filesystem errors",
+                        disk.mount_point
+                    )
+                    .red()
+                );
+            }
+            if let Some(secs) = disk.time_to_full_secs.filter(|_| disk.full_soon) {
+                println!(
+                    "{}",
+                    format!("Warning: {} is projected to be full in {}", disk.mount_point, format_eta(secs))
+                        .red()
+                        .bold()
+                );
+            }
+            if disk.usage_percent >= DISK_FULL_PERCENT {
+                println!(
+                    "{}",
+                    format!("Warning: {} is {:.1}% full", disk.mount_point, disk.usage_percent).red()
+                );
+            }
+            if let Some(percent) = disk.inode_usage_percent.filter(|p| *p >= DISK_FULL_PERCENT) {
+                println!(
+                    "{}",
+                    format!(
+                        "Warning: {} has used {:.1}% of its inodes; new files will fail with \"No space left on device\"",
+                        disk.mount_point, percent
+                    )
+                    .red()
+                );
+            }
+            println!();
         }
     }
 
-    if let Some(network) = network_info {
+    if let Some(disk_io) = &info.disk_io {
+        println!("{}", "Disk I/O".bold().cyan());
+
+        if let Some(ref error) = disk_io.error {
+            println!("{}", error.red());
+        } else {
+            let mut io_table = Table::new();
+            io_table.load_preset(UTF8_FULL);
+            io_table.set_header(vec![
+                Cell::new("Device").fg(comfy_table::Color::Yellow),
+                Cell::new("r/s").fg(comfy_table::Color::Yellow),
+                Cell::new("w/s").fg(comfy_table::Color::Yellow),
+                Cell::new("Read/s").fg(comfy_table::Color::Yellow),
+                Cell::new("Write/s").fg(comfy_table::Color::Yellow),
+                Cell::new("r_await").fg(comfy_table::Color::Yellow),
+                Cell::new("w_await").fg(comfy_table::Color::Yellow),
+                Cell::new("svctm").fg(comfy_table::Color::Yellow),
+                Cell::new("aqu-sz").fg(comfy_table::Color::Yellow),
+                Cell::new("%util").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for device in &disk_io.devices {
+                let util = Cell::new(format!("{:.1}", device.utilization_percent));
+                io_table.add_row(vec![
+                    Cell::new(&device.device),
+                    Cell::new(format!("{:.1}", device.reads_per_sec)),
+                    Cell::new(format!("{:.1}", device.writes_per_sec)),
+                    Cell::new(format_bytes(device.read_bytes_per_sec as u64)),
+                    Cell::new(format_bytes(device.write_bytes_per_sec as u64)),
+                    Cell::new(format!("{:.2} ms", device.read_await_ms)),
+                    Cell::new(format!("{:.2} ms", device.write_await_ms)),
+                    Cell::new(format!("{:.2} ms", device.service_time_ms)),
+                    Cell::new(format!("{:.2}", device.queue_depth)),
+                    if device.utilization_percent >= DISK_SATURATED_PERCENT {
+                        util.fg(comfy_table::Color::Red)
+                    } else {
+                        util
+                    },
+                ]);
+            }
+
+            println!("{io_table}");
+            println!("Sampled over {:.1}s\n", disk_io.interval_secs);
+        }
+    }
+
+    if let Some(block) = &info.block_devices {
+        println!("{}", "Block Devices".bold().cyan());
+
+        if let Some(ref error) = block.error {
+            println!("{}", error.red());
+        } else {
+            let mut block_table = Table::new();
+            block_table.load_preset(UTF8_FULL);
+            block_table.set_header(vec![
+                Cell::new("Name").fg(comfy_table::Color::Yellow),
+                Cell::new("Type").fg(comfy_table::Color::Yellow),
+                Cell::new("Size").fg(comfy_table::Color::Yellow),
+                Cell::new("Model").fg(comfy_table::Color::Yellow),
+                Cell::new("Media").fg(comfy_table::Color::Yellow),
+                Cell::new("Scheduler").fg(comfy_table::Color::Yellow),
+                Cell::new("Mount Points").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for device in &block.devices {
+                add_block_device_rows(&mut block_table, device, "", None);
+            }
+
+            println!("{block_table}\n");
+        }
+    }
+
+    if let Some(storage) = &info.storage {
+        println!("{}", "Storage Health".bold().cyan());
+
+        if let Some(ref error) = storage.error {
+            println!("{}", error.red());
+        } else {
+            if storage.md_arrays.is_empty() {
+                println!("No software RAID arrays");
+            } else {
+                let mut md_table = Table::new();
+                md_table.load_preset(UTF8_FULL);
+                md_table.set_header(vec![
+                    Cell::new("Array").fg(comfy_table::Color::Yellow),
+                    Cell::new("Level").fg(comfy_table::Color::Yellow),
+                    Cell::new("State").fg(comfy_table::Color::Yellow),
+                    Cell::new("Size").fg(comfy_table::Color::Yellow),
+                    Cell::new("Members").fg(comfy_table::Color::Yellow),
+                    Cell::new("Status").fg(comfy_table::Color::Yellow),
+                    Cell::new("Sync").fg(comfy_table::Color::Yellow),
+                ]);
+
+                for array in &storage.md_arrays {
+                    let members: Vec<String> = array
+                        .members
+                        .iter()
+                        .map(|m| {
+                            let flag = if m.faulty {
+                                " (faulty)"
+                            } else if m.spare {
+                                " (spare)"
+                            } else {
+                                ""
+                            };
+                            format!("{}{}", m.name, flag)
+                        })
+                        .collect();
+                    let status = match (array.active_disks, array.raid_disks, &array.status) {
+                        (Some(active), Some(total), Some(slots)) => format!("{}/{} [{}]", active, total, slots),
+                        (Some(active), Some(total), None) => format!("{}/{}", active, total),
+                        _ => "-".to_string(),
+                    };
+                    let status_cell = if array.is_inactive() {
+                        Cell::new(format!("{} INACTIVE", status)).fg(comfy_table::Color::Red)
+                    } else if array.degraded {
+                        Cell::new(format!("{} DEGRADED", status)).fg(comfy_table::Color::Red)
+                    } else {
+                        Cell::new(status).fg(comfy_table::Color::Green)
+                    };
+                    let sync = match &array.sync {
+                        Some(sync) => {
+                            let mut text = format!("{} {:.1}%", sync.action, sync.progress_percent);
+                            if let Some(ref finish) = sync.finish {
+                                text.push_str(&format!(", {} left", finish));
+                            }
+                            text
+                        }
+                        None => "-".to_string(),
+                    };
+
+                    md_table.add_row(vec![
+                        Cell::new(&array.name),
+                        Cell::new(array.level.as_deref().unwrap_or("-")),
+                        Cell::new(&array.state),
+                        Cell::new(array.size.map(format_bytes).unwrap_or_else(|| "-".to_string())),
+                        Cell::new(members.join(", ")),
+                        status_cell,
+                        Cell::new(sync),
+                    ]);
+                }
+
+                println!("{md_table}");
+
+                for array in &storage.md_arrays {
+                    let problem = if array.is_inactive() {
+                        "inactive"
+                    } else if array.degraded {
+                        "degraded"
+                    } else {
+                        continue;
+                    };
+                    println!(
+                        "{}",
+                        format!("Warning: RAID array {} is {}", array.name, problem).red().bold()
+                    );
+                }
+            }
+
+            if !storage.lvm_volumes.is_empty() {
+                let mut lvm_table = Table::new();
+                lvm_table.load_preset(UTF8_FULL);
+                lvm_table.set_header(vec![
+                    Cell::new("Logical Volume").fg(comfy_table::Color::Yellow),
+                    Cell::new("Volume Group").fg(comfy_table::Color::Yellow),
+                    Cell::new("Device").fg(comfy_table::Color::Yellow),
+                    Cell::new("Size").fg(comfy_table::Color::Yellow),
+                    Cell::new("Backed By").fg(comfy_table::Color::Yellow),
+                ]);
+
+                for volume in &storage.lvm_volumes {
+                    let device = if volume.suspended {
+                        Cell::new(format!("{} (suspended)", volume.kernel_name)).fg(comfy_table::Color::Red)
+                    } else {
+                        Cell::new(&volume.kernel_name)
+                    };
+                    lvm_table.add_row(vec![
+                        Cell::new(volume.logical_volume.as_deref().unwrap_or(&volume.name)),
+                        Cell::new(volume.volume_group.as_deref().unwrap_or("-")),
+                        device,
+                        Cell::new(format_bytes(volume.size)),
+                        Cell::new(volume.devices.join(", ")),
+                    ]);
+                }
+
+                println!("{lvm_table}");
+            }
+            println!();
+        }
+    }
+
+    if let Some(audit) = &info.mount_audit {
+        println!("{}", "Mount Audit".bold().cyan());
+
+        if let Some(ref error) = audit.error {
+            println!("{}", error.red());
+        } else {
+            if audit.entries.is_empty() {
+                println!("No mounts configured in fstab or mount units");
+            } else {
+                let mut audit_table = Table::new();
+                audit_table.load_preset(UTF8_FULL);
+                audit_table.set_header(vec![
+                    Cell::new("Mount Point").fg(comfy_table::Color::Yellow),
+                    Cell::new("Source").fg(comfy_table::Color::Yellow),
+                    Cell::new("Type").fg(comfy_table::Color::Yellow),
+                    Cell::new("Configured In").fg(comfy_table::Color::Yellow),
+                    Cell::new("Status").fg(comfy_table::Color::Yellow),
+                    Cell::new("Details").fg(comfy_table::Color::Yellow),
+                ]);
+
+                for entry in &audit.entries {
+                    let status = match entry.status {
+                        MountAuditStatus::Mounted => Cell::new("mounted").fg(comfy_table::Color::Green),
+                        MountAuditStatus::Mismatch => Cell::new("mismatch").fg(comfy_table::Color::Yellow),
+                        MountAuditStatus::Failed => Cell::new("NOT MOUNTED").fg(comfy_table::Color::Red),
+                        MountAuditStatus::NotAutomatic => Cell::new("noauto"),
+                    };
+                    let mut details = entry.differences.clone();
+                    if entry.status == MountAuditStatus::Failed && entry.nofail {
+                        details.push("nofail".to_string());
+                    }
+                    audit_table.add_row(vec![
+                        Cell::new(&entry.mount_point),
+                        Cell::new(&entry.spec),
+                        Cell::new(&entry.fs_type),
+                        Cell::new(&entry.origin),
+                        status,
+                        Cell::new(details.join(", ")),
+                    ]);
+                }
+
+                println!("{audit_table}");
+
+                for entry in audit.entries.iter().filter(|e| e.status == MountAuditStatus::Failed) {
+                    println!(
+                        "{}",
+                        format!("Warning: {} ({}) is not mounted", entry.mount_point, entry.spec).red().bold()
+                    );
+                }
+            }
+
+            if !audit.unlisted.is_empty() {
+                println!("{}", "Mounted but not in fstab".bold());
+                for mount in &audit.unlisted {
+                    println!("  {} ({}, {})", mount.mount_point, mount.device, mount.file_system);
+                }
+            }
+            println!();
+        }
+    }
+
+    if let Some(network) = &info.network {
         println!("{}", "Network Information".bold().cyan());
         for iface in &network.interfaces {
             let mut net_table = Table::new();
             net_table.load_preset(UTF8_FULL);
             net_table.add_row(Row::from(vec![
                 Cell::new("Interface").fg(comfy_table::Color::Yellow),
-                Cell::new(&iface.name),
+                Cell::new(format!("{} ({})", iface.name, iface.class.label())),
+            ]));
+            if let Some(ref state) = iface.oper_state {
+                let state_cell = match state.as_str() {
+                    "up" => Cell::new(state).fg(comfy_table::Color::Green),
+                    "down" | "lower-layer-down" => Cell::new(state).fg(comfy_table::Color::Red),
+                    _ => Cell::new(state),
+                };
+                net_table.add_row(Row::from(vec![
+                    Cell::new("State").fg(comfy_table::Color::Yellow),
+                    state_cell,
+                ]));
+            }
+            if let Some(ref mac) = iface.mac_address {
+                net_table.add_row(Row::from(vec![
+                    Cell::new("MAC").fg(comfy_table::Color::Yellow),
+                    Cell::new(mac),
+                ]));
+            }
+            if !iface.ipv4_addresses.is_empty() {
+                net_table.add_row(Row::from(vec![
+                    Cell::new("IPv4").fg(comfy_table::Color::Yellow),
+                    Cell::new(iface.ipv4_addresses.join("\n")),
+                ]));
+            }
+            if !iface.ipv6_addresses.is_empty() {
+                net_table.add_row(Row::from(vec![
+                    Cell::new("IPv6").fg(comfy_table::Color::Yellow),
+                    Cell::new(iface.ipv6_addresses.join("\n")),
+                ]));
+            }
+            if let Some(mtu) = iface.mtu {
+                net_table.add_row(Row::from(vec![
+                    Cell::new("MTU").fg(comfy_table::Color::Yellow),
+                    Cell::new(mtu.to_string()),
+                ]));
+            }
+            let link = match (iface.speed_mbps, &iface.duplex) {
+                (Some(speed), Some(duplex)) => Some(format!("{} Mb/s, {} duplex", speed, duplex)),
+                (Some(speed), None) => Some(format!("{} Mb/s", speed)),
+                (None, Some(duplex)) => Some(format!("{} duplex", duplex)),
+                (None, None) => None,
+            };
+            if let Some(link) = link {
+                net_table.add_row(Row::from(vec![
+                    Cell::new("Link").fg(comfy_table::Color::Yellow),
+                    Cell::new(link),
+                ]));
+            }
+            net_table.add_row(Row::from(vec![
+                Cell::new("RX Rate").fg(comfy_table::Color::Yellow),
+                Cell::new(format!(
+                    "{}/s ({:.1} pkt/s)",
+                    format_bytes(iface.rx_bytes_per_sec as u64),
+                    iface.rx_packets_per_sec
+                )),
+            ]));
+            net_table.add_row(Row::from(vec![
+                Cell::new("TX Rate").fg(comfy_table::Color::Yellow),
+                Cell::new(format!(
+                    "{}/s ({:.1} pkt/s)",
+                    format_bytes(iface.tx_bytes_per_sec as u64),
+                    iface.tx_packets_per_sec
+                )),
+            ]));
+            net_table.add_row(Row::from(vec![
+                Cell::new("Session RX / TX").fg(comfy_table::Color::Yellow),
+                Cell::new(format!(
+                    "{} / {}",
+                    format_bytes(iface.session_received),
+                    format_bytes(iface.session_transmitted)
+                )),
             ]));
             net_table.add_row(Row::from(vec![
                 Cell::new("Received").fg(comfy_table::Color::Yellow),
@@ -246,9 +844,439 @@ fn display_text(
             ]));
             println!("{net_table}\n");
         }
+        if network.hidden_count > 0 {
+            println!(
+                "{} interface(s) hidden; use --all-interfaces to show loopback and veth\n",
+                network.hidden_count
+            );
+        }
+    }
+
+    if let Some(routes) = &info.routes {
+        println!("{}", "Routing Table".bold().cyan());
+
+        if let Some(ref error) = routes.error {
+            println!("{}", error.red());
+        } else {
+            let mut route_table = Table::new();
+            route_table.load_preset(UTF8_FULL);
+            route_table.set_header(vec![
+                Cell::new("Destination").fg(comfy_table::Color::Yellow),
+                Cell::new("Gateway").fg(comfy_table::Color::Yellow),
+                Cell::new("Interface").fg(comfy_table::Color::Yellow),
+                Cell::new("Metric").fg(comfy_table::Color::Yellow),
+                Cell::new("Flags").fg(comfy_table::Color::Yellow),
+                Cell::new("Table").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for route in &routes.routes {
+                let destination = if route.is_default {
+                    Cell::new(format!("default ({})", route.destination)).fg(comfy_table::Color::Green)
+                } else {
+                    Cell::new(&route.destination)
+                };
+                route_table.add_row(vec![
+                    destination,
+                    Cell::new(route.gateway.map(|g| g.to_string()).unwrap_or_else(|| "-".to_string())),
+                    Cell::new(&route.interface),
+                    Cell::new(route.metric.to_string()),
+                    Cell::new(&route.flags),
+                    Cell::new(&route.table),
+                ]);
+            }
+
+            println!("{route_table}");
+
+            // The local/main/default rules exist everywhere; only show them alongside custom ones
+            if routes.rules.iter().any(|r| !r.is_standard()) {
+                let mut rule_table = Table::new();
+                rule_table.load_preset(UTF8_FULL);
+                rule_table.set_header(vec![
+                    Cell::new("Priority").fg(comfy_table::Color::Yellow),
+                    Cell::new("Family").fg(comfy_table::Color::Yellow),
+                    Cell::new("Selector").fg(comfy_table::Color::Yellow),
+                    Cell::new("Table").fg(comfy_table::Color::Yellow),
+                ]);
+                for rule in &routes.rules {
+                    rule_table.add_row(vec![
+                        Cell::new(rule.priority.to_string()),
+                        Cell::new(&rule.family),
+                        Cell::new(&rule.selector),
+                        Cell::new(rule.table.as_deref().unwrap_or("-")),
+                    ]);
+                }
+                println!("{rule_table}");
+            }
+            println!();
+        }
+    }
+
+    if let Some(neighbors) = &info.neighbors {
+        println!("{}", "Neighbors".bold().cyan());
+
+        if let Some(ref error) = neighbors.error {
+            println!("{}", error.red());
+        } else if neighbors.neighbors.is_empty() {
+            println!("No neighbor entries\n");
+        } else {
+            let mut neighbor_table = Table::new();
+            neighbor_table.load_preset(UTF8_FULL);
+            neighbor_table.set_header(vec![
+                Cell::new("Address").fg(comfy_table::Color::Yellow),
+                Cell::new("MAC").fg(comfy_table::Color::Yellow),
+                Cell::new("Interface").fg(comfy_table::Color::Yellow),
+                Cell::new("State").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for neighbor in &neighbors.neighbors {
+                let state = match neighbor.state.as_str() {
+                    "FAILED" => Cell::new(&neighbor.state).fg(comfy_table::Color::Red),
+                    "INCOMPLETE" => Cell::new(&neighbor.state).fg(comfy_table::Color::Yellow),
+                    "REACHABLE" | "PERMANENT" | "COMPLETE" => Cell::new(&neighbor.state).fg(comfy_table::Color::Green),
+                    _ => Cell::new(&neighbor.state),
+                };
+                let address = if neighbor.is_router {
+                    format!("{} (router)", neighbor.address)
+                } else {
+                    neighbor.address.to_string()
+                };
+                neighbor_table.add_row(vec![
+                    Cell::new(address),
+                    Cell::new(neighbor.mac_address.as_deref().unwrap_or("-")),
+                    Cell::new(&neighbor.interface),
+                    state,
+                ]);
+            }
+
+            println!("{neighbor_table}");
+
+            let failed = neighbors.failed_count();
+            if failed > 0 {
+                println!(
+                    "{}",
+                    format!("Warning: {} neighbor(s) did not answer address resolution (FAILED)", failed).red()
+                );
+            }
+            println!();
+        }
+    }
+
+    if let Some(netns) = &info.namespaces {
+        println!("{}", "Network Namespaces".bold().cyan());
+
+        if let Some(ref error) = netns.error {
+            println!("{}", error.red());
+        } else {
+            for namespace in &netns.namespaces {
+                let mut title = format!("net:[{}]", namespace.inode);
+                if namespace.is_current {
+                    title.push_str(" (current)");
+                }
+                if let Some(ref name) = namespace.name {
+                    title.push_str(&format!(" \"{}\"", name));
+                }
+                let mut details = Vec::new();
+                if let Some(ref leader) = namespace.leader {
+                    details.push(format!("pid {} {}", leader.pid, leader.name));
+                }
+                if namespace.process_count > 1 {
+                    details.push(format!("{} processes", namespace.process_count));
+                }
+                for container in &namespace.containers {
+                    details.push(format!("{} {}", container.runtime, &container.id[..12.min(container.id.len())]));
+                }
+                if details.is_empty() {
+                    println!("{}", title.bold());
+                } else {
+                    println!("{} - {}", title.bold(), details.join(", "));
+                }
+
+                if let Some(ref error) = namespace.error {
+                    println!("{}", error.red());
+                    continue;
+                }
+
+                let mut ns_table = Table::new();
+                ns_table.load_preset(UTF8_FULL);
+                ns_table.set_header(vec![
+                    Cell::new("Interface").fg(comfy_table::Color::Yellow),
+                    Cell::new("Addresses").fg(comfy_table::Color::Yellow),
+                    Cell::new("Received").fg(comfy_table::Color::Yellow),
+                    Cell::new("Transmitted").fg(comfy_table::Color::Yellow),
+                    Cell::new("Errors (RX/TX)").fg(comfy_table::Color::Yellow),
+                ]);
+                for iface in &namespace.interfaces {
+                    let addresses: Vec<&str> = iface
+                        .ipv4_addresses
+                        .iter()
+                        .chain(&iface.ipv6_addresses)
+                        .map(String::as_str)
+                        .collect();
+                    let errors = format!("{}/{}", iface.errors_received, iface.errors_transmitted);
+                    let errors = if iface.errors_received + iface.errors_transmitted > 0 {
+                        Cell::new(errors).fg(comfy_table::Color::Red)
+                    } else {
+                        Cell::new(errors)
+                    };
+                    ns_table.add_row(vec![
+                        Cell::new(&iface.name),
+                        Cell::new(if addresses.is_empty() { "-".to_string() } else { addresses.join("\n") }),
+                        Cell::new(format!("{} ({} pkts)", format_bytes(iface.received), iface.packets_received)),
+                        Cell::new(format!("{} ({} pkts)", format_bytes(iface.transmitted), iface.packets_transmitted)),
+                        errors,
+                    ]);
+                }
+                println!("{ns_table}");
+                if !namespace.entered {
+                    println!("{}", "IPv4 addresses need root to enter the namespace".yellow());
+                }
+            }
+
+            if netns.hidden_processes > 0 {
+                println!(
+                    "{}",
+                    format!(
+                        "{} processes belong to other users; run as root to see their namespaces",
+                        netns.hidden_processes
+                    )
+                    .yellow()
+                );
+            }
+            println!();
+        }
+    }
+
+    if let Some(sockets) = &info.sockets {
+        println!("{}", "Sockets".bold().cyan());
+
+        if let Some(ref error) = sockets.error {
+            println!("{}", error.red());
+        } else {
+            let summary: Vec<String> = sockets
+                .summary
+                .iter()
+                .map(|(state, count)| format!("{} {}", state, count))
+                .collect();
+            println!("{}", summary.join(", "));
+
+            if sockets.sockets.is_empty() {
+                println!("No matching sockets");
+            } else {
+                let mut socket_table = Table::new();
+                socket_table.load_preset(UTF8_FULL);
+                socket_table.set_header(vec![
+                    Cell::new("Proto").fg(comfy_table::Color::Yellow),
+                    Cell::new("State").fg(comfy_table::Color::Yellow),
+                    Cell::new("Recv-Q").fg(comfy_table::Color::Yellow),
+                    Cell::new("Send-Q").fg(comfy_table::Color::Yellow),
+                    Cell::new("Local Address").fg(comfy_table::Color::Yellow),
+                    Cell::new("Peer Address").fg(comfy_table::Color::Yellow),
+                    Cell::new("Process").fg(comfy_table::Color::Yellow),
+                ]);
+
+                for socket in &sockets.sockets {
+                    let state = match socket.state.as_str() {
+                        "CLOSE_WAIT" => Cell::new(&socket.state).fg(comfy_table::Color::Red),
+                        "LISTEN" => Cell::new(&socket.state).fg(comfy_table::Color::Green),
+                        _ => Cell::new(&socket.state),
+                    };
+                    let process = match (socket.pid, &socket.process_name) {
+                        (Some(pid), Some(name)) => format!("{} ({})", name, pid),
+                        _ => "-".to_string(),
+                    };
+                    socket_table.add_row(vec![
+                        Cell::new(&socket.protocol),
+                        state,
+                        Cell::new(socket.recv_queue.to_string()),
+                        Cell::new(socket.send_queue.to_string()),
+                        Cell::new(format_endpoint(socket, true)),
+                        Cell::new(format_endpoint(socket, false)),
+                        Cell::new(process),
+                    ]);
+                }
+
+                println!("{socket_table}");
+            }
+            println!();
+        }
+    }
+
+    if let Some(listening) = &info.listening_ports {
+        println!("{}", "Listening Ports".bold().cyan());
+
+        if let Some(ref error) = listening.error {
+            println!("{}", error.red());
+        } else if listening.ports.is_empty() {
+            println!("No listening ports\n");
+        } else {
+            let mut ports_table = Table::new();
+            ports_table.load_preset(UTF8_FULL);
+            ports_table.set_header(vec![
+                Cell::new("Proto").fg(comfy_table::Color::Yellow),
+                Cell::new("Address").fg(comfy_table::Color::Yellow),
+                Cell::new("Port").fg(comfy_table::Color::Yellow),
+                Cell::new("PID").fg(comfy_table::Color::Yellow),
+                Cell::new("Process").fg(comfy_table::Color::Yellow),
+                Cell::new("User").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for port in &listening.ports {
+                let address = if port.all_interfaces {
+                    Cell::new(port.address.to_string()).fg(comfy_table::Color::Yellow)
+                } else {
+                    Cell::new(port.address.to_string())
+                };
+                ports_table.add_row(vec![
+                    Cell::new(&port.protocol),
+                    address,
+                    Cell::new(port.port.to_string()),
+                    Cell::new(port.pid.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string())),
+                    Cell::new(port.process_name.as_deref().unwrap_or("-")),
+                    Cell::new(port.user.clone().unwrap_or_else(|| format!("uid {}", port.uid))),
+                ]);
+            }
+
+            println!("{ports_table}");
+
+            for port in listening.ports.iter().filter(|p| p.all_interfaces) {
+                println!(
+                    "{}",
+                    format!(
+                        "Warning: {} port {} ({}) is bound to {} and reachable on every interface",
+                        port.protocol,
+                        port.port,
+                        port.process_name.as_deref().unwrap_or("unknown process"),
+                        port.address
+                    )
+                    .red()
+                );
+            }
+            println!();
+        }
+    }
+
+    if let Some(stats) = &info.protocol_stats {
+        println!("{}", "Protocol Statistics".bold().cyan());
+
+        if let Some(ref error) = stats.error {
+            println!("{}", error.red());
+        } else {
+            let mut stats_table = Table::new();
+            stats_table.load_preset(UTF8_FULL);
+            stats_table.set_header(vec![
+                Cell::new("Counter").fg(comfy_table::Color::Yellow),
+                Cell::new("Since Boot").fg(comfy_table::Color::Yellow),
+                Cell::new("Rate").fg(comfy_table::Color::Yellow),
+            ]);
+
+            for counter in &stats.counters {
+                // Every counter here is an error count, so any increase is worth a look
+                let rate = format!("{:.1}/s", counter.per_sec);
+                let rate = if counter.per_sec > 0.0 {
+                    Cell::new(rate).fg(comfy_table::Color::Red)
+                } else {
+                    Cell::new(rate)
+                };
+                stats_table.add_row(vec![
+                    Cell::new(format!("{} ({})", counter.description, counter.name)),
+                    Cell::new(counter.total.to_string()),
+                    rate,
+                ]);
+            }
+
+            println!("{stats_table}");
+
+            let sockets = &stats.sockets;
+            let limit = match (sockets.tcp_memory_pressure_bytes, sockets.tcp_memory_max_bytes) {
+                (Some(pressure), Some(max)) => format!(
+                    " (pressure at {}, max {})",
+                    format_bytes(pressure),
+                    format_bytes(max)
+                ),
+                _ => String::new(),
+            };
+            println!(
+                "TCP sockets: {} in use, {} orphaned, {} in TIME_WAIT; memory {}{}",
+                sockets.tcp_in_use,
+                sockets.tcp_orphaned,
+                sockets.tcp_time_wait,
+                format_bytes(sockets.tcp_memory_bytes),
+                limit
+            );
+            println!(
+                "UDP sockets: {} in use; memory {}",
+                sockets.udp_in_use,
+                format_bytes(sockets.udp_memory_bytes)
+            );
+            if sockets.under_pressure() {
+                println!(
+                    "{}",
+                    "Warning: TCP memory is above the tcp_mem pressure threshold; the kernel is shrinking socket buffers".red()
+                );
+            }
+            println!();
+        }
+    }
+
+    if let Some(conntrack) = &info.conntrack {
+        println!("{}", "Connection Tracking".bold().cyan());
+
+        if let Some(ref error) = conntrack.error {
+            println!("{}", error.red());
+        } else {
+            let usage = format!(
+                "{} of {} entries ({:.1}%)",
+                conntrack.count, conntrack.max, conntrack.usage_percent
+            );
+            if conntrack.is_over_threshold() {
+                println!("{}", usage.red());
+                println!(
+                    "{}",
+                    format!(
+                        "Warning: conntrack table usage has reached the {:.0}% threshold; new connections are dropped once it fills",
+                        conntrack.threshold_percent
+                    )
+                    .red()
+                );
+            } else {
+                println!("{}", usage.green());
+            }
+
+            if let Some(ref error) = conntrack.entries_error {
+                println!("{}", error.yellow());
+            } else if !conntrack.protocols.is_empty() {
+                let protocols: Vec<String> = conntrack
+                    .protocols
+                    .iter()
+                    .map(|p| format!("{} {}", p.key, p.count))
+                    .collect();
+                println!("By protocol: {}", protocols.join(", "));
+
+                let mut talkers_table = Table::new();
+                talkers_table.load_preset(UTF8_FULL);
+                talkers_table.set_header(vec![
+                    Cell::new("Top Sources").fg(comfy_table::Color::Yellow),
+                    Cell::new("Entries").fg(comfy_table::Color::Yellow),
+                    Cell::new("Top Destinations").fg(comfy_table::Color::Yellow),
+                    Cell::new("Entries").fg(comfy_table::Color::Yellow),
+                ]);
+                let rows = conntrack.top_sources.len().max(conntrack.top_destinations.len());
+                let cells = |list: &[ConntrackCount], i: usize| match list.get(i) {
+                    Some(entry) => (Cell::new(&entry.key), Cell::new(entry.count.to_string())),
+                    None => (Cell::new(""), Cell::new("")),
+                };
+                for i in 0..rows {
+                    let (source, source_count) = cells(&conntrack.top_sources, i);
+                    let (destination, destination_count) = cells(&conntrack.top_destinations, i);
+                    talkers_table.add_row(vec![source, source_count, destination, destination_count]);
+                }
+                println!("{talkers_table}");
+            }
+            println!();
+        }
     }
 
-    if let Some(temp) = temp_info {
+    if let Some(temp) = &info.temperature {
         if !temp.components.is_empty() {
             println!("{}", "Temperature Information".bold().cyan());
             for component in &temp.components {
@@ -279,35 +1307,125 @@ fn display_text(
         }
     }
 
-    if let Some(processes) = process_info {
+    if let Some(sensors) = &info.sensors {
+        println!("{}", "Sensors".bold().cyan());
+
+        if let Some(ref error) = sensors.error {
+            println!("{}", error.red());
+        } else if sensors.chips.is_empty() {
+            println!("No hwmon devices found\n");
+        } else {
+            for chip in &sensors.chips {
+                println!("{}", chip.name.bold());
+                let mut sensor_table = Table::new();
+                sensor_table.load_preset(UTF8_FULL);
+                sensor_table.set_header(vec![
+                    Cell::new("Sensor").fg(comfy_table::Color::Yellow),
+                    Cell::new("Value").fg(comfy_table::Color::Yellow),
+                    Cell::new("Min").fg(comfy_table::Color::Yellow),
+                    Cell::new("Max").fg(comfy_table::Color::Yellow),
+                    Cell::new("Critical").fg(comfy_table::Color::Yellow),
+                    Cell::new("Status").fg(comfy_table::Color::Yellow),
+                ]);
+
+                let groups = [
+                    (&chip.temperatures, "°C"),
+                    (&chip.fans, "RPM"),
+                    (&chip.voltages, "V"),
+                    (&chip.power, "W"),
+                    (&chip.current, "A"),
+                ];
+                for (readings, unit) in groups {
+                    for reading in readings {
+                        let format = |value: f64| format_sensor(value, unit);
+                        let limit = |limit: Option<f64>| limit.map(format).unwrap_or_else(|| "-".to_string());
+                        let (value, status) = if reading.alarm {
+                            (
+                                Cell::new(format(reading.value)).fg(comfy_table::Color::Red),
+                                Cell::new("ALARM").fg(comfy_table::Color::Red),
+                            )
+                        } else {
+                            (Cell::new(format(reading.value)), Cell::new("OK").fg(comfy_table::Color::Green))
+                        };
+                        sensor_table.add_row(vec![
+                            Cell::new(&reading.label),
+                            value,
+                            Cell::new(limit(reading.min)),
+                            Cell::new(limit(reading.max)),
+                            Cell::new(limit(reading.critical)),
+                            status,
+                        ]);
+                    }
+                }
+                println!("{sensor_table}");
+            }
+
+            let alarms = sensors.alarm_count();
+            if alarms > 0 {
+                println!("{}", format!("Warning: {} sensor(s) report an alarm or fault", alarms).red());
+            }
+            println!();
+        }
+    }
+
+    if let Some(processes) = &info.processes {
         println!("{}", "Process Information".bold().cyan());
+        let show_growth = processes.processes.iter().any(|p| p.memory_growth.is_some());
         let mut proc_table = Table::new();
         proc_table.load_preset(UTF8_FULL);
-        proc_table.set_header(vec![
+        let mut header = vec![
             Cell::new("PID").fg(comfy_table::Color::Yellow),
             Cell::new("Name").fg(comfy_table::Color::Yellow),
             Cell::new("CPU %").fg(comfy_table::Color::Yellow),
             Cell::new("Memory").fg(comfy_table::Color::Yellow),
-            Cell::new("Disk Read").fg(comfy_table::Color::Yellow),
-            Cell::new("Disk Write").fg(comfy_table::Color::Yellow),
-        ]);
+            Cell::new("Read/s").fg(comfy_table::Color::Yellow),
+            Cell::new("Write/s").fg(comfy_table::Color::Yellow),
+            Cell::new("Read Total").fg(comfy_table::Color::Yellow),
+            Cell::new("Write Total").fg(comfy_table::Color::Yellow),
+        ];
+        if show_growth {
+            header.push(Cell::new("Mem Growth").fg(comfy_table::Color::Yellow));
+        }
+        proc_table.set_header(header);
 
         for proc in &processes.processes {
-            proc_table.add_row(vec![
+            let mut row = vec![
                 Cell::new(proc.pid.to_string()),
                 Cell::new(&proc.name),
                 Cell::new(format!("{:.2}", proc.cpu_usage)),
                 Cell::new(format_bytes(proc.memory_usage)),
-                Cell::new(format_bytes(proc.disk_read)),
-                Cell::new(format_bytes(proc.disk_write)),
-            ]);
+                Cell::new(format!("{}/s", format_bytes(proc.disk_read_rate as u64))),
+                Cell::new(format!("{}/s", format_bytes(proc.disk_write_rate as u64))),
+                Cell::new(format_bytes(proc.disk_read_total)),
+                Cell::new(format_bytes(proc.disk_write_total)),
+            ];
+            if show_growth {
+                let suspect = processes.memory_leaks.iter().any(|l| l.pid == proc.pid);
+                let cell = Cell::new(proc.memory_growth.map(format_rate).unwrap_or_default());
+                row.push(if suspect { cell.fg(comfy_table::Color::Red) } else { cell });
+            }
+            proc_table.add_row(row);
         }
 
         println!("{proc_table}");
+
+        if !processes.memory_leaks.is_empty() {
+            println!("\n{}", "Steady memory growth detected:".red().bold());
+            for leak in &processes.memory_leaks {
+                println!(
+                    "  {} (PID {}): {} now, {} over {} samples",
+                    leak.name,
+                    leak.pid,
+                    format_bytes(leak.memory_usage),
+                    format_rate(leak.growth_per_sec),
+                    leak.samples
+                );
+            }
+        }
         println!("\nTotal processes: {}\n", processes.total_count);
     }
 
-    if let Some(hosts) = hosts_info {
+    if let Some(hosts) = &info.hosts {
         if let Some(ref error) = hosts.error {
             println!("\n{}", "Hosts File Information".bold().cyan());
             println!("{}", error.red());
@@ -331,30 +1449,187 @@ fn display_text(
         }
     }
 
+    if let Some(dns) = &info.dns {
+        println!("\n{}", "DNS Resolver".bold().cyan());
+
+        if let Some(ref error) = dns.error {
+            println!("{}", error.red());
+        }
+        // resolv.conf problems do not stop the other sources, so show what is known
+        let mut dns_table = Table::new();
+        dns_table.load_preset(UTF8_FULL);
+        dns_table.add_row(Row::from(vec![
+            Cell::new("Nameservers").fg(comfy_table::Color::Yellow),
+            Cell::new(if dns.nameservers.is_empty() {
+                "-".to_string()
+            } else {
+                dns.nameservers.join("\n")
+            }),
+        ]));
+        if let Some(ref resolved) = dns.systemd_resolved {
+            dns_table.add_row(Row::from(vec![
+                Cell::new("systemd-resolved").fg(comfy_table::Color::Yellow),
+                Cell::new(if resolved.upstream_nameservers.is_empty() {
+                    "stub resolver, upstream unknown".to_string()
+                } else {
+                    format!("stub resolver, upstream {}", resolved.upstream_nameservers.join(", "))
+                }),
+            ]));
+        }
+        if !dns.search_domains.is_empty() {
+            dns_table.add_row(Row::from(vec![
+                Cell::new("Search Domains").fg(comfy_table::Color::Yellow),
+                Cell::new(dns.search_domains.join(" ")),
+            ]));
+        }
+        if !dns.options.is_empty() {
+            dns_table.add_row(Row::from(vec![
+                Cell::new("Options").fg(comfy_table::Color::Yellow),
+                Cell::new(dns.options.join(" ")),
+            ]));
+        }
+        dns_table.add_row(Row::from(vec![
+            Cell::new("Lookup Order").fg(comfy_table::Color::Yellow),
+            Cell::new(dns.hosts_sources.join(" → ")),
+        ]));
+        println!("{dns_table}");
+
+        if let Some(ref resolution) = dns.resolution {
+            let steps: Vec<String> = resolution
+                .steps
+                .iter()
+                .map(|step| format!("{}: {}", step.source, step.outcome))
+                .collect();
+            match resolution.answered_by {
+                Some(ref source) => {
+                    let via = match resolution.server {
+                        Some(ref server) => format!("{} ({})", source, server),
+                        None => source.clone(),
+                    };
+                    let addresses: Vec<String> = resolution.addresses.iter().map(|a| a.to_string()).collect();
+                    println!(
+                        "{} → {} via {} in {:.1} ms",
+                        resolution.name.bold(),
+                        addresses.join(", ").green(),
+                        via,
+                        resolution.elapsed_ms
+                    );
+                }
+                None => println!(
+                    "{}",
+                    format!("{} did not resolve", resolution.name).red()
+                ),
+            }
+            println!("  {}", steps.join(", "));
+        }
+        println!();
+    }
+
+    if let Some(zram) = &info.zram {
+        println!("\n{}", "Compressed Memory (zram/zswap)".bold().cyan());
+
+        if let Some(ref error) = zram.error {
+            println!("{}", error.red());
+        } else {
+            if zram.devices.is_empty() {
+                println!("No zram devices configured");
+            } else {
+                let mut zram_table = Table::new();
+                zram_table.load_preset(UTF8_FULL);
+                zram_table.set_header(vec![
+                    Cell::new("Device").fg(comfy_table::Color::Yellow),
+                    Cell::new("Algorithm").fg(comfy_table::Color::Yellow),
+                    Cell::new("Disk Size").fg(comfy_table::Color::Yellow),
+                    Cell::new("Original").fg(comfy_table::Color::Yellow),
+                    Cell::new("Compressed").fg(comfy_table::Color::Yellow),
+                    Cell::new("Memory Used").fg(comfy_table::Color::Yellow),
+                    Cell::new("Ratio").fg(comfy_table::Color::Yellow),
+                    Cell::new("Swap").fg(comfy_table::Color::Yellow),
+                ]);
+
+                for device in &zram.devices {
+                    let swap = match &device.swap {
+                        Some(swap) => format!(
+                            "{} / {} (prio {})",
+                            format_bytes(swap.used),
+                            format_bytes(swap.size),
+                            swap.priority
+                        ),
+                        None => "-".to_string(),
+                    };
+                    zram_table.add_row(vec![
+                        Cell::new(&device.name),
+                        Cell::new(device.algorithm.as_deref().unwrap_or("-")),
+                        Cell::new(format_bytes(device.disk_size)),
+                        Cell::new(format_bytes(device.orig_data_size)),
+                        Cell::new(format_bytes(device.compr_data_size)),
+                        Cell::new(format_bytes(device.mem_used_total)),
+                        Cell::new(format_ratio(device.compression_ratio)),
+                        Cell::new(swap),
+                    ]);
+                }
+
+                println!("{zram_table}");
+            }
+
+            if let Some(zswap) = &zram.zswap {
+                let mut zswap_table = Table::new();
+                zswap_table.load_preset(UTF8_FULL);
+                zswap_table.add_row(Row::from(vec![
+                    Cell::new("zswap").fg(comfy_table::Color::Yellow),
+                    Cell::new(if zswap.enabled { "Enabled" } else { "Disabled" }),
+                ]));
+                if let Some(ref compressor) = zswap.compressor {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Compressor").fg(comfy_table::Color::Yellow),
+                        Cell::new(compressor),
+                    ]));
+                }
+                if let Some(ref zpool) = zswap.zpool {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Zpool").fg(comfy_table::Color::Yellow),
+                        Cell::new(zpool),
+                    ]));
+                }
+                if let Some(percent) = zswap.max_pool_percent {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Max Pool").fg(comfy_table::Color::Yellow),
+                        Cell::new(format!("{}% of RAM", percent)),
+                    ]));
+                }
+                if let Some(size) = zswap.pool_size {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Pool Size").fg(comfy_table::Color::Yellow),
+                        Cell::new(format_bytes(size)),
+                    ]));
+                }
+                if let Some(pages) = zswap.stored_pages {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Stored Pages").fg(comfy_table::Color::Yellow),
+                        Cell::new(pages.to_string()),
+                    ]));
+                }
+                if let Some(bytes) = zswap.stored_bytes {
+                    zswap_table.add_row(Row::from(vec![
+                        Cell::new("Stored Data").fg(comfy_table::Color::Yellow),
+                        Cell::new(format_bytes(bytes)),
+                    ]));
+                }
+                zswap_table.add_row(Row::from(vec![
+                    Cell::new("Ratio").fg(comfy_table::Color::Yellow),
+                    Cell::new(format_ratio(zswap.compression_ratio)),
+                ]));
+                println!("{zswap_table}");
+            }
+        }
+    }
+
     println!();
     Ok(())
 }
 
-fn display_json(
-    system_info: &SystemInfo,
-    battery_info: &Option<BatteryInfo>,
-    disks_info: &Option<DisksInfo>,
-    network_info: &Option<NetworkInfo>,
-    temp_info: &Option<TemperatureInfo>,
-    process_info: &Option<ProcessInfo>,
-    hosts_info: &Option<HostsInfo>,
-) -> Result<()> {
-    let all_info = AllInfo {
-        system: system_info.clone(),
-        battery: battery_info.clone(),
-        disks: disks_info.clone(),
-        network: network_info.clone(),
-        temperature: temp_info.clone(),
-        processes: process_info.clone(),
-        hosts: hosts_info.clone(),
-    };
-
-    let json = serde_json::to_string_pretty(&all_info)?;
+fn display_json(info: &AllInfo) -> Result<()> {
+    let json = serde_json::to_string_pretty(info)?;
     println!("{}", json);
     Ok(())
 }
@@ -374,3 +1649,99 @@ fn format_bytes(bytes: u64) -> String {
         format!("{} B", bytes)
     }
 }
+
+fn format_rate(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_signed_bytes(bytes_per_sec))
+}
+
+fn format_signed_bytes(bytes: f64) -> String {
+    let sign = if bytes < 0.0 { "-" } else { "+" };
+    format!("{}{}", sign, format_bytes(bytes.abs() as u64))
+}
+
+/// Formats a socket address as `ip:port`, bracketing IPv6 addresses; port 0 is shown as `*`.
+fn format_endpoint(socket: &SocketEntry, local: bool) -> String {
+    let (address, port) = if local {
+        (socket.local_address, socket.local_port)
+    } else {
+        (socket.remote_address, socket.remote_port)
+    };
+    let port = if port == 0 { "*".to_string() } else { port.to_string() };
+    match address {
+        std::net::IpAddr::V6(v6) => format!("[{}]:{}", v6, port),
+        std::net::IpAddr::V4(v4) => format!("{}:{}", v4, port),
+    }
+}
+
+/// Formats a Unix timestamp as a UTC wall-clock time.
+fn format_clock(timestamp: u64) -> String {
+    let secs = timestamp % 86400;
+    format!("{:02}:{:02}:{:02} UTC", secs / 3600, (secs % 3600) / 60, secs % 60)
+}
+
+fn format_eta(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let hours = (seconds % 86400) / 3600;
+    let minutes = (seconds % 3600) / 60;
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else {
+        format!("{}h {}m", hours, minutes)
+    }
+}
+
+fn format_sensor(value: f64, unit: &str) -> String {
+    match unit {
+        "°C" => format!("{:.1}°C", value),
+        "RPM" => format!("{:.0} RPM", value),
+        "W" => format!("{:.2} W", value),
+        _ => format!("{:.3} {}", value, unit),
+    }
+}
+
+fn format_ratio(ratio: Option<f64>) -> String {
+    ratio.map(|r| format!("{:.2}x", r)).unwrap_or_else(|| "-".to_string())
+}
+
+fn usage_cell(percent: f32) -> Cell {
+    let cell = Cell::new(format!("{:.2}%", percent));
+    if percent >= DISK_FULL_PERCENT {
+        cell.fg(comfy_table::Color::Red)
+    } else {
+        cell
+    }
+}
+
+/// Adds a device and its descendants, drawing the hierarchy with lsblk-style branches.
+/// `is_last` is `None` for top-level devices.
+fn add_block_device_rows(table: &mut Table, device: &BlockDevice, indent: &str, is_last: Option<bool>) {
+    let (branch, child_indent) = match is_last {
+        None => ("", String::new()),
+        Some(true) => ("└─", format!("{}  ", indent)),
+        Some(false) => ("├─", format!("{}│ ", indent)),
+    };
+
+    let media = match device.rotational {
+        Some(true) => "HDD",
+        Some(false) => "SSD",
+        None => "-",
+    };
+    let mut mounts = device.mount_points.join(", ");
+    if let Some(ref file) = device.backing_file {
+        mounts = if mounts.is_empty() { file.clone() } else { format!("{} ({})", mounts, file) };
+    }
+
+    table.add_row(vec![
+        Cell::new(format!("{}{}{}", indent, branch, device.name)),
+        Cell::new(&device.device_type),
+        Cell::new(format_bytes(device.size)),
+        Cell::new(device.model.as_deref().unwrap_or("")),
+        Cell::new(media),
+        Cell::new(device.scheduler.as_deref().unwrap_or("")),
+        Cell::new(mounts),
+    ]);
+
+    for (i, child) in device.children.iter().enumerate() {
+        add_block_device_rows(table, child, &child_indent, Some(i + 1 == device.children.len()));
+    }
+}
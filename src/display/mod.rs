@@ -1,3 +1,3 @@
 mod formatter;
 
-pub use formatter::{OutputFormat, display_info};
+pub use formatter::{AllInfo, OutputFormat, display_du, display_info, display_probe};
@@ -2,5 +2,5 @@ pub mod collectors;
 pub mod display;
 pub mod cli;
 
-pub use collectors::{SystemInfo, BatteryInfo, DisksInfo, NetworkInfo, TemperatureInfo, ProcessInfo, HostsInfo};
-pub use display::{OutputFormat, display_info};
+pub use collectors::{SystemInfo, BatteryInfo, DisksInfo, DiskFilter, DiskHistory, MountWatcher, BlockDevicesInfo, StorageHealthInfo, MountAuditInfo, DiskIoInfo, DiskIoSampler, NetworkInfo, NetworkFilter, NetworkSampler, ProtocolStatsInfo, ProtocolStatsSampler, ConntrackInfo, SocketFilter, SocketsInfo, ListeningPortsInfo, RoutesInfo, NeighborsInfo, NetnsInfo, TemperatureInfo, SensorsInfo, ProcessInfo, ProcessHistory, ProcessSort, HostsInfo, DnsInfo, ZramInfo, DuInfo, ProbeInfo};
+pub use display::{AllInfo, OutputFormat, display_info};
@@ -3,11 +3,51 @@ use std::io::{self, Write};
 use std::thread;
 use std::time::Duration;
 use weni::{
-    BatteryInfo, DisksInfo, NetworkInfo, SystemInfo, TemperatureInfo, ProcessInfo, HostsInfo,
-    cli::CliArgs,
-    display::{display_info, OutputFormat},
+    BatteryInfo, DisksInfo, DiskIoSampler, BlockDevicesInfo, StorageHealthInfo, NetworkSampler, ProtocolStatsSampler, ConntrackInfo, SocketsInfo, ListeningPortsInfo, RoutesInfo, NeighborsInfo, NetnsInfo, SystemInfo, TemperatureInfo, SensorsInfo, ProcessInfo, ProcessHistory, ProcessSort, HostsInfo, DnsInfo, ZramInfo,
+    DuInfo, ProbeInfo, DiskHistory, DiskFilter, MountWatcher, MountAuditInfo,
+    cli::{CliArgs, Command},
+    display::{display_du, display_info, display_probe, AllInfo, OutputFormat},
 };
 
+/// History carried between ticks in watch mode.
+struct WatchState {
+    process_history: ProcessHistory,
+    diskio_sampler: DiskIoSampler,
+    network_sampler: NetworkSampler,
+    protocol_sampler: ProtocolStatsSampler,
+    disk_history: DiskHistory,
+    mount_watcher: MountWatcher,
+}
+
+impl WatchState {
+    fn new(args: &CliArgs) -> Result<Self> {
+        let disk_history = match &args.disk_history {
+            Some(path) => DiskHistory::load(path)
+                .map_err(|e| anyhow::anyhow!("Cannot load disk history {}: {}", path.display(), e))?,
+            None => DiskHistory::default(),
+        };
+
+        Ok(Self {
+            process_history: ProcessHistory::new(args.leak_window),
+            diskio_sampler: DiskIoSampler::new(),
+            network_sampler: NetworkSampler::new(),
+            protocol_sampler: ProtocolStatsSampler::new(),
+            disk_history,
+            mount_watcher: MountWatcher::new(),
+        })
+    }
+
+    /// Persists the disk history when `--disk-history` was given.
+    fn save(&self, args: &CliArgs) -> Result<()> {
+        if let Some(path) = &args.disk_history {
+            self.disk_history
+                .save(path)
+                .map_err(|e| anyhow::anyhow!("Cannot save disk history {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+}
+
 fn main() -> Result<()> {
     let args = CliArgs::parse()?;
 
@@ -16,6 +56,21 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(Command::Du(ref options)) = args.command {
+        let info = DuInfo::collect(options)
+            .map_err(|e| anyhow::anyhow!("Cannot scan {}: {}", options.path.display(), e))?;
+        return display_du(&info, options.tree, output_format(&args));
+    }
+
+    if let Some(Command::Probe(ref options)) = args.command {
+        let info = ProbeInfo::collect(options).map_err(|e| anyhow::anyhow!(e))?;
+        display_probe(&info, output_format(&args))?;
+        if info.has_failures() {
+            std::process::exit(2);
+        }
+        return Ok(());
+    }
+
     if args.watch {
         run_watch_mode(args)
     } else {
@@ -24,53 +79,69 @@ fn main() -> Result<()> {
 }
 
 fn run_once(args: CliArgs) -> Result<()> {
-    let (system_info, battery_info, disks_info, network_info, temp_info, process_info, hosts_info) = collect_info(&args);
+    let mut state = WatchState::new(&args)?;
+    let info = collect_info(&args, &mut state);
+    state.save(&args)?;
 
-    let format = if args.json {
-        OutputFormat::Json
-    } else {
-        OutputFormat::Text
-    };
+    display_info(&info, output_format(&args))?;
 
-    display_info(system_info, battery_info, disks_info, network_info, temp_info, process_info, hosts_info, format)?;
+    if args.check && info.has_failures() {
+        std::process::exit(2);
+    }
 
     Ok(())
 }
 
 fn run_watch_mode(args: CliArgs) -> Result<()> {
-    if args.json {
-        anyhow::bail!("Watch mode is not compatible with JSON output");
-    }
+    let mut state = WatchState::new(&args)?;
 
     loop {
-        clear_screen();
-        let (system_info, battery_info, disks_info, network_info, temp_info, process_info, hosts_info) = collect_info(&args);
-        display_info(
-            system_info,
-            battery_info,
-            disks_info,
-            network_info,
-            temp_info,
-            process_info,
-            hosts_info,
-            OutputFormat::Text,
-        )?;
-        println!("Press Ctrl+C to exit | Refreshing every {} seconds", args.interval);
+        let info = collect_info(&args, &mut state);
+        state.save(&args)?;
+        if args.json {
+            // One compact document per tick, so the output can be consumed as a stream;
+            // a closed pipe (e.g. `| head`) ends the stream quietly
+            let mut stdout = io::stdout().lock();
+            if let Err(e) = writeln!(stdout, "{}", serde_json::to_string(&info)?).and_then(|_| stdout.flush()) {
+                if e.kind() == io::ErrorKind::BrokenPipe {
+                    return Ok(());
+                }
+                return Err(e.into());
+            }
+        } else {
+            clear_screen();
+            display_info(&info, OutputFormat::Text)?;
+            println!("Press Ctrl+C to exit | Refreshing every {} seconds", args.interval);
+        }
         io::stdout().flush()?;
         thread::sleep(Duration::from_secs(args.interval));
     }
 }
 
-fn collect_info(args: &CliArgs) -> (SystemInfo, Option<BatteryInfo>, Option<DisksInfo>, Option<NetworkInfo>, Option<TemperatureInfo>, Option<ProcessInfo>, Option<HostsInfo>) {
+fn collect_info(args: &CliArgs, state: &mut WatchState) -> AllInfo {
     let collect_cpu = args.show_all || args.show_cpu;
     let collect_memory = args.show_all || args.show_memory;
     let collect_system = args.show_all || args.show_system;
     let collect_battery = args.show_all || args.show_battery;
     let collect_disk = args.show_all || args.show_disk;
+    let collect_diskio = args.show_diskio;
+    let collect_blockdev = args.show_blockdev;
+    let collect_storage = args.show_storage;
+    let collect_fstab = args.show_fstab;
     let collect_network = args.show_all || args.show_network;
+    let collect_routes = args.show_routes;
+    let collect_neighbors = args.show_neighbors;
+    let collect_netns = args.show_netns;
+    let collect_sockets = args.show_sockets;
+    let collect_ports = args.show_ports;
+    let collect_netstat = args.show_netstat;
+    let collect_conntrack = args.show_conntrack;
     let collect_temp = args.show_all || args.show_temp;
+    let collect_sensors = args.show_sensors;
     let collect_process = args.show_all || args.show_process;
     let collect_hosts = args.show_hosts;
+    let collect_dns = args.show_dns;
+    let collect_zram = args.show_zram;
 
     let system_info = SystemInfo::new(collect_cpu, collect_memory, collect_system);
 
@@ -81,13 +152,91 @@ fn collect_info(args: &CliArgs) -> (SystemInfo, Option<BatteryInfo>, Option<Disk
     };
 
     let disks_info = if collect_disk {
-        Some(DisksInfo::collect())
+        let mut info = DisksInfo::collect(&args.disk_filter);
+        state
+            .disk_history
+            .record(&mut info, args.fill_horizon_hours * 3600);
+        if args.watch {
+            state.mount_watcher.record(&mut info);
+        }
+        Some(info)
+    } else {
+        None
+    };
+
+    let diskio_info = if collect_diskio {
+        Some(state.diskio_sampler.sample())
+    } else {
+        None
+    };
+
+    let blockdev_info = if collect_blockdev {
+        Some(BlockDevicesInfo::collect())
+    } else {
+        None
+    };
+
+    let storage_info = if collect_storage {
+        Some(StorageHealthInfo::collect())
+    } else {
+        None
+    };
+
+    let mount_audit_info = if collect_fstab {
+        match &disks_info {
+            Some(disks) => Some(MountAuditInfo::collect(disks)),
+            None => Some(MountAuditInfo::collect(&DisksInfo::collect(&DiskFilter::default()))),
+        }
     } else {
         None
     };
 
     let network_info = if collect_network {
-        Some(NetworkInfo::collect())
+        Some(state.network_sampler.sample(&args.network_filter))
+    } else {
+        None
+    };
+
+    let routes_info = if collect_routes {
+        Some(RoutesInfo::collect())
+    } else {
+        None
+    };
+
+    let neighbors_info = if collect_neighbors {
+        Some(NeighborsInfo::collect())
+    } else {
+        None
+    };
+
+    let netns_info = if collect_netns {
+        Some(NetnsInfo::collect())
+    } else {
+        None
+    };
+
+    let sockets_info = if collect_sockets {
+        Some(SocketsInfo::collect(&args.socket_filter))
+    } else {
+        None
+    };
+
+    let ports_info = if collect_ports {
+        // Every process is needed for the join, not just the top N shown in --process
+        let processes = ProcessInfo::collect(None, ProcessSort::Memory);
+        Some(ListeningPortsInfo::collect(&processes, &args.socket_filter.ports))
+    } else {
+        None
+    };
+
+    let protocol_stats_info = if collect_netstat {
+        Some(state.protocol_sampler.sample())
+    } else {
+        None
+    };
+
+    let conntrack_info = if collect_conntrack {
+        Some(ConntrackInfo::collect(args.conntrack_threshold))
     } else {
         None
     };
@@ -98,6 +247,12 @@ fn collect_info(args: &CliArgs) -> (SystemInfo, Option<BatteryInfo>, Option<Disk
         None
     };
 
+    let sensors_info = if collect_sensors {
+        Some(SensorsInfo::collect())
+    } else {
+        None
+    };
+
     let process_info = if collect_process {
         // If showing all info and no specific options set, use defaults: top 10, sorted by CPU
         let top = if args.show_all && args.process_top.is_none() {
@@ -105,8 +260,22 @@ fn collect_info(args: &CliArgs) -> (SystemInfo, Option<BatteryInfo>, Option<Disk
         } else {
             args.process_top
         };
-        let sort_cpu = args.show_all || args.process_sort_cpu;
-        Some(ProcessInfo::collect(top, sort_cpu))
+        let sort = args.process_sort.unwrap_or(if args.show_all {
+            ProcessSort::Cpu
+        } else {
+            ProcessSort::Memory
+        });
+        if args.watch {
+            // Track every process so growth is not lost when one drops out of the top N
+            let mut info = ProcessInfo::collect(None, sort);
+            state.process_history.record(&mut info);
+            if let Some(n) = top {
+                info.truncate(n);
+            }
+            Some(info)
+        } else {
+            Some(ProcessInfo::collect(top, sort))
+        }
     } else {
         None
     };
@@ -117,7 +286,49 @@ fn collect_info(args: &CliArgs) -> (SystemInfo, Option<BatteryInfo>, Option<Disk
         None
     };
 
-    (system_info, battery_info, disks_info, network_info, temp_info, process_info, hosts_info)
+    let dns_info = if collect_dns {
+        Some(DnsInfo::collect(args.resolve_name.as_deref()))
+    } else {
+        None
+    };
+
+    let zram_info = if collect_zram {
+        Some(ZramInfo::collect())
+    } else {
+        None
+    };
+
+    AllInfo {
+        system: system_info,
+        battery: battery_info,
+        disks: disks_info,
+        disk_io: diskio_info,
+        block_devices: blockdev_info,
+        storage: storage_info,
+        mount_audit: mount_audit_info,
+        network: network_info,
+        routes: routes_info,
+        neighbors: neighbors_info,
+        namespaces: netns_info,
+        sockets: sockets_info,
+        listening_ports: ports_info,
+        protocol_stats: protocol_stats_info,
+        conntrack: conntrack_info,
+        temperature: temp_info,
+        sensors: sensors_info,
+        processes: process_info,
+        hosts: hosts_info,
+        dns: dns_info,
+        zram: zram_info,
+    }
+}
+
+fn output_format(args: &CliArgs) -> OutputFormat {
+    if args.json {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Text
+    }
 }
 
 fn clear_screen() {